@@ -0,0 +1,226 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    parse2,
+    punctuated::Punctuated,
+    token::Brace,
+    Attribute, Error, Ident, ItemStruct, Path, Result, Token, Type, TypeParamBound,
+};
+
+type Bounds = Punctuated<TypeParamBound, Token![+]>;
+
+struct Route {
+    variant: Ident,
+    /// Fields passed to the handler's `new`, when it isn't bound with `#[operation_handler]`
+    fields: Option<Punctuated<Ident, Token![,]>>,
+    handler: Path,
+    /// Marked with `#[response]`, the handler returns the factory's response type
+    responds: bool,
+}
+
+impl Parse for Route {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut responds = false;
+        for attr in input.call(Attribute::parse_outer)? {
+            if !attr.path().is_ident("response") {
+                return Err(Error::new_spanned(attr, "unknown route attribute"));
+            }
+            responds = true;
+        }
+        let variant = input.parse()?;
+        let fields = if input.peek(Brace) {
+            let content;
+            braced!(content in input);
+            Some(content.parse_terminated(Ident::parse, Token![,])?)
+        } else {
+            None
+        };
+        input.parse::<Token![=>]>()?;
+        let handler = input.call(Path::parse_mod_style)?;
+        Ok(Self {
+            variant,
+            fields,
+            handler,
+            responds,
+        })
+    }
+}
+
+struct Routes {
+    enum_path: Path,
+    routes: Punctuated<Route, Token![,]>,
+}
+
+impl Parse for Routes {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let enum_path = input.call(Path::parse_mod_style)?;
+        let content;
+        braced!(content in input);
+        let routes = content.parse_terminated(Route::parse, Token![,])?;
+        Ok(Self { enum_path, routes })
+    }
+}
+
+struct EnvHandlerArgs {
+    runtime: Bounds,
+    state: Bounds,
+    operation: Routes,
+    message: Routes,
+    response: Option<Type>,
+}
+
+impl Parse for EnvHandlerArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut runtime = None;
+        let mut state = None;
+        let mut operation = None;
+        let mut message = None;
+        let mut response = None;
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            match key.to_string().as_str() {
+                "runtime" => runtime = Some(Bounds::parse_separated_nonempty(input)?),
+                "state" => state = Some(Bounds::parse_separated_nonempty(input)?),
+                "operation" => operation = Some(input.parse()?),
+                "message" => message = Some(input.parse()?),
+                "response" => response = Some(input.parse()?),
+                _ => return Err(Error::new_spanned(key, "unknown env_handler argument")),
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        let missing = |name: &str| input.error(format!("env_handler requires `{}`", name));
+        Ok(Self {
+            runtime: runtime.ok_or_else(|| missing("runtime"))?,
+            state: state.ok_or_else(|| missing("state"))?,
+            operation: operation.ok_or_else(|| missing("operation"))?,
+            message: message.ok_or_else(|| missing("message"))?,
+            response,
+        })
+    }
+}
+
+fn match_arms(
+    routes: &Routes,
+    value: &Ident,
+    handler_ty: &TokenStream,
+    has_response: bool,
+) -> Result<Vec<TokenStream>> {
+    let enum_path = &routes.enum_path;
+
+    routes
+        .routes
+        .iter()
+        .map(
+            |Route {
+                 variant,
+                 fields,
+                 handler,
+                 responds,
+             }| {
+                if *responds && !has_response {
+                    return Err(Error::new_spanned(
+                        variant,
+                        "`#[response]` route requires env_handler `response`",
+                    ));
+                }
+                // Handlers without response only implement `Handler<M>`
+                let boxed = if has_response && !*responds {
+                    quote! {
+                        ::std::boxed::Box::new(::base::handler::interfaces::NoResponse(handler))
+                    }
+                } else {
+                    quote! { ::std::boxed::Box::new(handler) }
+                };
+                Ok(match fields {
+                    Some(fields) => {
+                        let fields = fields.iter().collect::<Vec<_>>();
+                        quote! {
+                            #enum_path::#variant { #(ref #fields,)* .. } => {
+                                let handler = #handler::new(runtime, state, #(#fields,)*);
+                                ::core::result::Result::Ok(#boxed as #handler_ty)
+                            }
+                        }
+                    }
+                    None => quote! {
+                        #enum_path::#variant { .. } => #handler::from_variant(runtime, state, #value)
+                            .map(|handler| #boxed as #handler_ty),
+                    },
+                })
+            },
+        )
+        .collect()
+}
+
+pub fn expand(args: TokenStream, input: TokenStream) -> Result<TokenStream> {
+    let args: EnvHandlerArgs = parse2(args)?;
+    let item: ItemStruct = parse2(input)?;
+
+    let name = &item.ident;
+    let runtime_bounds = &args.runtime;
+    let state_bounds = &args.state;
+    let operation = &args.operation.enum_path;
+    let message = &args.message.enum_path;
+    let response = match &args.response {
+        Some(response) => quote! { #response },
+        None => quote! { () },
+    };
+
+    let handler_ty = quote! {
+        ::std::boxed::Box<dyn ::base::handler::interfaces::Handler<#message, #response>>
+    };
+    let result_ty = quote! {
+        ::core::result::Result<#handler_ty, ::base::handler::errors::HandlerError>
+    };
+
+    let op = Ident::new("op", proc_macro2::Span::call_site());
+    let msg = Ident::new("msg", proc_macro2::Span::call_site());
+    let has_response = args.response.is_some();
+    let operation_arms = match_arms(&args.operation, &op, &handler_ty, has_response)?;
+    let message_arms = match_arms(&args.message, &msg, &handler_ty, has_response)?;
+
+    Ok(quote! {
+        #item
+
+        impl<R, S> ::base::handler::interfaces::HandlerFactory<R, S> for #name
+        where
+            R: #runtime_bounds + 'static,
+            S: #state_bounds + 'static,
+        {
+            type Operation = #operation;
+            type Message = #message;
+            type Response = #response;
+
+            #[allow(unused_variables)]
+            fn new_operation_handler(
+                runtime: R,
+                state: S,
+                #op: &#operation,
+            ) -> #result_ty {
+                match *#op {
+                    #(#operation_arms)*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn new_message_handler(
+                runtime: R,
+                state: S,
+                #msg: &#message,
+            ) -> #result_ty {
+                match *#msg {
+                    #(#message_arms)*
+                }
+            }
+        }
+    })
+}
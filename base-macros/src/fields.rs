@@ -0,0 +1,49 @@
+use syn::{Error, Field, Fields, Ident, ItemStruct, Result, Type};
+
+pub struct HandlerFields<'a> {
+    pub runtime: &'a Field,
+    pub state: &'a Field,
+    pub params: Vec<&'a Field>,
+}
+
+impl<'a> HandlerFields<'a> {
+    pub fn parse(item: &'a ItemStruct) -> Result<Self> {
+        let Fields::Named(fields) = &item.fields else {
+            return Err(Error::new_spanned(
+                &item.ident,
+                "handler must be a struct with named fields",
+            ));
+        };
+
+        let mut runtime = None;
+        let mut state = None;
+        let mut params = Vec::new();
+
+        for field in &fields.named {
+            match field.ident.as_ref().map(Ident::to_string).as_deref() {
+                Some("runtime") => runtime = Some(field),
+                Some("state") | Some("_state") => state = Some(field),
+                _ => params.push(field),
+            }
+        }
+
+        Ok(Self {
+            runtime: runtime
+                .ok_or_else(|| Error::new_spanned(&item.ident, "handler has no `runtime` field"))?,
+            state: state
+                .ok_or_else(|| Error::new_spanned(&item.ident, "handler has no `state` field"))?,
+            params,
+        })
+    }
+
+    pub fn param_idents(&self) -> Vec<&Ident> {
+        self.params
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .collect()
+    }
+
+    pub fn param_types(&self) -> Vec<&Type> {
+        self.params.iter().map(|field| &field.ty).collect()
+    }
+}
@@ -0,0 +1,41 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse2, Error, ItemStruct, Result};
+
+use crate::fields::HandlerFields;
+
+pub fn expand(args: TokenStream, input: TokenStream) -> Result<TokenStream> {
+    if !args.is_empty() {
+        return Err(Error::new_spanned(args, "#[handler] takes no arguments"));
+    }
+
+    let item: ItemStruct = parse2(input)?;
+    let fields = HandlerFields::parse(&item)?;
+
+    let name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    let runtime_ty = &fields.runtime.ty;
+    let state_ty = &fields.state.ty;
+    let state_ident = &fields.state.ident;
+    let param_idents = fields.param_idents();
+    let param_types = fields.param_types();
+
+    Ok(quote! {
+        #item
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn new(
+                runtime: #runtime_ty,
+                state: #state_ty,
+                #(#param_idents: &#param_types,)*
+            ) -> Self {
+                Self {
+                    runtime,
+                    #state_ident: state,
+                    #(#param_idents: ::core::clone::Clone::clone(#param_idents),)*
+                }
+            }
+        }
+    })
+}
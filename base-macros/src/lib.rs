@@ -0,0 +1,65 @@
+use proc_macro::TokenStream;
+
+mod env_handler;
+mod fields;
+mod handler;
+mod operation_handler;
+
+/// Generates `new(runtime, state, &params..)` for a handler struct.
+///
+/// The `runtime` and `state` (or `_state`) fields are moved in, every other field
+/// is cloned from the borrowed operation or message payload.
+#[proc_macro_attribute]
+pub fn handler(args: TokenStream, input: TokenStream) -> TokenStream {
+    handler::expand(args.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Binds a handler struct to one `Operation` or `Message` variant.
+///
+/// Generates `from_variant(runtime, state, &variant)` which destructures the variant
+/// by the handler's field names and forwards them to the `#[handler]` constructor.
+/// Any other variant gives `HandlerError::InvalidOperationAndMessage`.
+#[proc_macro_attribute]
+pub fn operation_handler(args: TokenStream, input: TokenStream) -> TokenStream {
+    operation_handler::expand(args.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `base::handler::interfaces::HandlerFactory` for the annotated struct.
+///
+/// ```ignore
+/// #[env_handler(
+///     runtime = ContractRuntimeContext + AccessControl,
+///     state = StateInterface,
+///     operation = Operation {
+///         Transfer => TransferHandler,
+///         #[response]
+///         BatchTransfer => BatchTransferHandler,
+///     },
+///     message = Message {
+///         Transfer => TransferMessageHandler,
+///     },
+///     response = OperationResult,
+/// )]
+/// pub struct HandlerFactory;
+/// ```
+///
+/// A route may name the variant fields instead, `GrantRole { owner, role } => GrantRoleHandler`
+/// calls `GrantRoleHandler::new(runtime, state, owner, role)` with the borrowed fields. It's
+/// how the shared handlers of `base::handler::roles` are routed.
+///
+/// `response` is optional and defaults to `()`. Only the routes marked `#[response]`
+/// implement `Handler<M, Response>`, the others implement `Handler<M>` and are
+/// wrapped in `NoResponse`.
+///
+/// The generated match has no wildcard arm, so a variant without a handler fails to compile,
+/// see `tests/ui/missing_handler.rs`.
+#[proc_macro_attribute]
+pub fn env_handler(args: TokenStream, input: TokenStream) -> TokenStream {
+    env_handler::expand(args.into(), input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse2, ItemStruct, Path, Result};
+
+use crate::fields::HandlerFields;
+
+pub fn expand(args: TokenStream, input: TokenStream) -> Result<TokenStream> {
+    let variant: Path = parse2(args)?;
+    let item: ItemStruct = parse2(input)?;
+    let fields = HandlerFields::parse(&item)?;
+
+    let mut enum_path = variant.clone();
+    enum_path.segments.pop();
+    enum_path.segments.pop_punct();
+
+    let name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    let runtime_ty = &fields.runtime.ty;
+    let state_ty = &fields.state.ty;
+    let param_idents = fields.param_idents();
+
+    Ok(quote! {
+        #item
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn from_variant(
+                runtime: #runtime_ty,
+                state: #state_ty,
+                variant: &#enum_path,
+            ) -> ::core::result::Result<Self, ::base::handler::errors::HandlerError> {
+                #[allow(unreachable_patterns)]
+                match variant {
+                    #variant { #(#param_idents,)* .. } => {
+                        ::core::result::Result::Ok(Self::new(runtime, state, #(#param_idents,)*))
+                    }
+                    _ => ::core::result::Result::Err(
+                        ::base::handler::errors::HandlerError::InvalidOperationAndMessage,
+                    ),
+                }
+            }
+        }
+    })
+}
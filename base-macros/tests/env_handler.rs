@@ -0,0 +1,8 @@
+//! Compile-time checks of the code generated by `#[env_handler]`.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+#[test]
+fn variant_without_handler_fails_to_compile() {
+    trybuild::TestCases::new().compile_fail("tests/ui/missing_handler.rs");
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler, types::HandlerOutcome};
+use base_macros::env_handler;
+
+pub trait Runtime {}
+pub trait State {}
+
+pub enum Operation {
+    Ping,
+    Pong,
+}
+
+pub enum Message {
+    Ping,
+}
+
+pub struct PingHandler<R, S> {
+    pub runtime: R,
+    pub state: S,
+}
+
+impl<R, S> PingHandler<R, S> {
+    pub fn new(runtime: R, state: S) -> Self {
+        Self { runtime, state }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R, S> Handler<Message> for PingHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome<Message>, HandlerError> {
+        Ok(HandlerOutcome::default())
+    }
+}
+
+// `Operation::Pong` has no handler
+#[env_handler(
+    runtime = Runtime,
+    state = State,
+    operation = Operation {
+        Ping {} => PingHandler,
+    },
+    message = Message {
+        Ping {} => PingHandler,
+    },
+)]
+pub struct HandlerFactory;
+
+fn main() {}
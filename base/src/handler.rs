@@ -0,0 +1,5 @@
+pub mod errors;
+pub mod executor;
+pub mod interfaces;
+pub mod roles;
+pub mod types;
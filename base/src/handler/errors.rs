@@ -0,0 +1,96 @@
+use std::fmt;
+
+use crate::runtime::errors::RuntimeError;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+/// An error raised while handling an operation or a message, split by the layer which
+/// raised it.
+#[derive(Clone, Debug, Deserialize, Eq, Error, PartialEq, Serialize)]
+pub enum HandlerError {
+    #[error("Invalid operation and message")]
+    InvalidOperationAndMessage,
+
+    #[error("Permission denied: {0}")]
+    Permission(LayerError),
+
+    #[error("Runtime error: {0}")]
+    Runtime(LayerError),
+
+    #[error("State error: {0}")]
+    State(LayerError),
+}
+
+/// Error of one layer carried by a `HandlerError`.
+///
+/// `code` is the variant of the source error and `detail` the whole source error as JSON,
+/// so callers can match on the code or decode the typed error with its context.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct LayerError {
+    pub code: String,
+    pub message: String,
+    pub detail: String,
+}
+
+impl LayerError {
+    pub fn new<E: std::error::Error + Serialize>(err: &E) -> Self {
+        let detail = serde_json::to_value(err).unwrap_or_default();
+        let code = match &detail {
+            serde_json::Value::String(code) => code.clone(),
+            serde_json::Value::Object(fields) => fields.keys().next().cloned().unwrap_or_default(),
+            _ => String::new(),
+        };
+        Self {
+            code,
+            message: err.to_string(),
+            detail: detail.to_string(),
+        }
+    }
+
+    /// Decodes the source error, none when it isn't an `E`
+    pub fn decode<E: DeserializeOwned>(&self) -> Option<E> {
+        serde_json::from_str(&self.detail).ok()
+    }
+}
+
+impl fmt::Display for LayerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl HandlerError {
+    /// Wraps an error raised by the runtime, keeping permission errors apart
+    pub fn runtime<E: Into<HandlerError>>(err: E) -> Self {
+        err.into()
+    }
+
+    /// Wraps an error raised by the application state
+    pub fn state<E: std::error::Error + Serialize>(err: E) -> Self {
+        Self::State(LayerError::new(&err))
+    }
+
+    /// Wraps a permission check failed by the handler itself
+    pub fn permission<E: std::error::Error + Serialize>(err: E) -> Self {
+        Self::Permission(LayerError::new(&err))
+    }
+
+    /// Error of the layer which failed, none when no handler matched
+    pub fn layer(&self) -> Option<&LayerError> {
+        match self {
+            Self::InvalidOperationAndMessage => None,
+            Self::Permission(err) | Self::Runtime(err) | Self::State(err) => Some(err),
+        }
+    }
+}
+
+impl From<RuntimeError> for HandlerError {
+    fn from(err: RuntimeError) -> Self {
+        match err {
+            RuntimeError::AccountPermission(_)
+            | RuntimeError::NotApplicationCreator { .. }
+            | RuntimeError::MissingRole { .. } => Self::Permission(LayerError::new(&err)),
+            _ => Self::Runtime(LayerError::new(&err)),
+        }
+    }
+}
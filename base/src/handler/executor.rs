@@ -0,0 +1,62 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::{
+    errors::HandlerError,
+    interfaces::HandlerFactory,
+    types::{HandlerOutcome, OutgoingMessage},
+};
+use crate::{
+    interfaces::{runtime::contract::ContractRuntimeContext, unit_of_work::UnitOfWork},
+    runtime::{
+        contract::ContractRuntimeAdapter, errors::RuntimeError, unit_of_work::ViewUnitOfWork,
+    },
+};
+use linera_sdk::{views::linera_views::views::ClonableView, Contract, ContractRuntime};
+
+/// Runs the handler `F` builds for `op` or `msg` as one unit of work over `state`.
+///
+/// A failed handler leaves the state as it was at `begin`. The handler's messages are
+/// only sent once its state changes are committed, the other side effects of the
+/// outcome are left to the caller.
+pub async fn execute<F, T, V, A>(
+    runtime: Rc<RefCell<ContractRuntime<T>>>,
+    state: Rc<RefCell<V>>,
+    state_adapter: A,
+    op: Option<&F::Operation>,
+    msg: Option<&F::Message>,
+) -> Result<HandlerOutcome<T::Message, F::Response>, HandlerError>
+where
+    T: Contract,
+    V: ClonableView,
+    F: HandlerFactory<ContractRuntimeAdapter<T, V>, A, Message = T::Message>,
+{
+    let runtime_context = ContractRuntimeAdapter::new(runtime.clone(), state.clone());
+    let mut unit_of_work = ViewUnitOfWork::new(state.clone());
+
+    unit_of_work
+        .begin()
+        .map_err(|err| HandlerError::state(RuntimeError::from(err)))?;
+    let result = match F::new(runtime_context, state_adapter, op, msg) {
+        Ok(mut handler) => handler.handle().await,
+        Err(err) => Err(err),
+    };
+    let mut outcome = match result {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            unit_of_work.rollback();
+            return Err(err);
+        }
+    };
+    unit_of_work.commit();
+
+    let mut runtime_context = ContractRuntimeAdapter::new(runtime, state);
+    for OutgoingMessage {
+        authenticated,
+        destination,
+        message,
+    } in outcome.messages.drain(..)
+    {
+        runtime_context.send_message(authenticated, destination, message);
+    }
+    Ok(outcome)
+}
@@ -0,0 +1,58 @@
+use super::errors::HandlerError;
+use super::types::HandlerOutcome;
+use async_trait::async_trait;
+
+#[async_trait(?Send)]
+pub trait Handler<M, R = ()> {
+    async fn handle(&mut self) -> Result<HandlerOutcome<M, R>, HandlerError>;
+}
+
+/// Serves a handler without response where the factory expects one
+pub struct NoResponse<H>(pub H);
+
+#[async_trait(?Send)]
+impl<M, R, H: Handler<M>> Handler<M, R> for NoResponse<H> {
+    async fn handle(&mut self) -> Result<HandlerOutcome<M, R>, HandlerError> {
+        let outcome = self.0.handle().await?;
+        Ok(HandlerOutcome {
+            messages: outcome.messages,
+            updated_accounts: outcome.updated_accounts,
+            response: None,
+        })
+    }
+}
+
+/// Builds the handler of an application's operation or message from its runtime and state
+pub trait HandlerFactory<R, S> {
+    type Operation;
+    type Message;
+    /// Response returned by the operation handlers
+    type Response;
+
+    fn new_operation_handler(
+        runtime: R,
+        state: S,
+        op: &Self::Operation,
+    ) -> Result<Box<dyn Handler<Self::Message, Self::Response>>, HandlerError>;
+
+    fn new_message_handler(
+        runtime: R,
+        state: S,
+        msg: &Self::Message,
+    ) -> Result<Box<dyn Handler<Self::Message, Self::Response>>, HandlerError>;
+
+    fn new(
+        runtime: R,
+        state: S,
+        op: Option<&Self::Operation>,
+        msg: Option<&Self::Message>,
+    ) -> Result<Box<dyn Handler<Self::Message, Self::Response>>, HandlerError> {
+        if let Some(op) = op {
+            return Self::new_operation_handler(runtime, state, op);
+        }
+        if let Some(msg) = msg {
+            return Self::new_message_handler(runtime, state, msg);
+        }
+        Err(HandlerError::InvalidOperationAndMessage)
+    }
+}
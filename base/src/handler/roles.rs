@@ -0,0 +1,106 @@
+//! Role management handlers shared by the applications, routed from their own
+//! `GrantRole`, `RevokeRole` and `RenounceRole` variants by `env_handler`.
+
+use std::marker::PhantomData;
+
+use super::{errors::HandlerError, interfaces::Handler, types::HandlerOutcome};
+use crate::{interfaces::access_control::AccessControl, types::Role};
+use async_trait::async_trait;
+use linera_sdk::linera_base_types::AccountOwner;
+
+pub struct GrantRoleHandler<R, S, M> {
+    runtime: R,
+    _state: S,
+    _message: PhantomData<M>,
+
+    owner: AccountOwner,
+    role: Role,
+}
+
+impl<R, S, M> GrantRoleHandler<R, S, M> {
+    pub fn new(runtime: R, state: S, owner: &AccountOwner, role: &Role) -> Self {
+        Self {
+            runtime,
+            _state: state,
+            _message: PhantomData,
+            owner: *owner,
+            role: *role,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: AccessControl, S, M> Handler<M> for GrantRoleHandler<R, S, M> {
+    async fn handle(&mut self) -> Result<HandlerOutcome<M>, HandlerError> {
+        self.runtime
+            .grant_role(self.owner, self.role)
+            .await
+            .map_err(HandlerError::runtime)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
+
+pub struct RevokeRoleHandler<R, S, M> {
+    runtime: R,
+    _state: S,
+    _message: PhantomData<M>,
+
+    owner: AccountOwner,
+    role: Role,
+}
+
+impl<R, S, M> RevokeRoleHandler<R, S, M> {
+    pub fn new(runtime: R, state: S, owner: &AccountOwner, role: &Role) -> Self {
+        Self {
+            runtime,
+            _state: state,
+            _message: PhantomData,
+            owner: *owner,
+            role: *role,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: AccessControl, S, M> Handler<M> for RevokeRoleHandler<R, S, M> {
+    async fn handle(&mut self) -> Result<HandlerOutcome<M>, HandlerError> {
+        self.runtime
+            .revoke_role(self.owner, self.role)
+            .await
+            .map_err(HandlerError::runtime)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
+
+pub struct RenounceRoleHandler<R, S, M> {
+    runtime: R,
+    _state: S,
+    _message: PhantomData<M>,
+
+    role: Role,
+}
+
+impl<R, S, M> RenounceRoleHandler<R, S, M> {
+    pub fn new(runtime: R, state: S, role: &Role) -> Self {
+        Self {
+            runtime,
+            _state: state,
+            _message: PhantomData,
+            role: *role,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: AccessControl, S, M> Handler<M> for RenounceRoleHandler<R, S, M> {
+    async fn handle(&mut self) -> Result<HandlerOutcome<M>, HandlerError> {
+        self.runtime
+            .renounce_role(self.role)
+            .await
+            .map_err(HandlerError::runtime)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -0,0 +1,43 @@
+use linera_sdk::linera_base_types::{AccountOwner, ChainId};
+
+/// Message queued by a handler, sent once its state changes are committed
+#[derive(Debug)]
+pub struct OutgoingMessage<M> {
+    pub authenticated: bool,
+    pub destination: ChainId,
+    pub message: M,
+}
+
+#[derive(Debug)]
+pub struct HandlerOutcome<M, R = ()> {
+    pub messages: Vec<OutgoingMessage<M>>,
+    /// Accounts whose value changed, pushed to the dependent applications after commit
+    pub updated_accounts: Vec<AccountOwner>,
+    /// Returned to the caller of the operation, message handlers leave it empty
+    pub response: Option<R>,
+}
+
+impl<M, R> HandlerOutcome<M, R> {
+    pub fn send_message(&mut self, authenticated: bool, destination: ChainId, message: M) {
+        self.messages.push(OutgoingMessage {
+            authenticated,
+            destination,
+            message,
+        });
+    }
+
+    pub fn with_response(mut self, response: R) -> Self {
+        self.response = Some(response);
+        self
+    }
+}
+
+impl<M, R> Default for HandlerOutcome<M, R> {
+    fn default() -> Self {
+        Self {
+            messages: Vec::new(),
+            updated_accounts: Vec::new(),
+            response: None,
+        }
+    }
+}
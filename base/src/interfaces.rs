@@ -0,0 +1,3 @@
+pub mod access_control;
+pub mod runtime;
+pub mod unit_of_work;
@@ -0,0 +1,38 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::runtime::contract::ContractRuntimeContext;
+use crate::{handler::errors::HandlerError, types::Role};
+use async_trait::async_trait;
+use linera_sdk::{linera_base_types::AccountOwner, views::ViewError};
+
+#[async_trait(?Send)]
+pub trait AccessControl {
+    type Error: std::fmt::Debug + std::error::Error + Into<HandlerError> + 'static;
+
+    fn only_application_creator(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the authenticated account holding `role`, or an error if there's none
+    async fn only_role(&mut self, role: Role) -> Result<AccountOwner, Self::Error>;
+    async fn grant_role(&mut self, owner: AccountOwner, role: Role) -> Result<(), Self::Error>;
+    /// Fails rather than revoking the admin role of the last admin
+    async fn revoke_role(&mut self, owner: AccountOwner, role: Role) -> Result<(), Self::Error>;
+    /// Fails rather than renouncing the admin role of the last admin
+    async fn renounce_role(&mut self, role: Role) -> Result<(), Self::Error>;
+}
+
+/// Storage of the roles granted to each account
+#[async_trait(?Send)]
+pub trait RoleStore {
+    async fn roles(&self, owner: AccountOwner) -> Result<Vec<Role>, ViewError>;
+    fn set_roles(&mut self, owner: AccountOwner, roles: Vec<Role>) -> Result<(), ViewError>;
+    /// Accounts granted `role` itself, admins aren't implied holders of the other roles
+    async fn holders(&self, role: Role) -> Result<Vec<AccountOwner>, ViewError>;
+}
+
+/// Contract runtime checking the authenticated accounts against a `RoleStore`, every
+/// such runtime gets `AccessControl`
+pub trait RoleContext: ContractRuntimeContext {
+    type Roles: RoleStore;
+
+    fn role_store(&self) -> Rc<RefCell<Self::Roles>>;
+}
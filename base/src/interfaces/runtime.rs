@@ -0,0 +1,3 @@
+pub mod base;
+pub mod contract;
+pub mod service;
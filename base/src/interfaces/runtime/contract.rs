@@ -0,0 +1,32 @@
+use super::base::BaseRuntimeContext;
+use crate::handler::errors::HandlerError;
+use linera_sdk::{
+    abi::ContractAbi,
+    linera_base_types::{AccountOwner, ApplicationId, ChainId},
+};
+
+pub trait ContractRuntimeContext: BaseRuntimeContext {
+    type Error: std::fmt::Debug + std::error::Error + Into<HandlerError> + 'static;
+    type Message;
+    type Parameters;
+
+    fn authenticated_signer(&mut self) -> Option<AccountOwner>;
+    fn require_authenticated_signer(&mut self) -> Result<AccountOwner, Self::Error>;
+    fn authenticated_caller_id(&mut self) -> Option<ApplicationId>;
+
+    /// With `authenticated` the signer of the current block is forwarded to the receiver.
+    /// Messages are tracked, a message its receiver rejects bounces back to the sender
+    fn send_message(&mut self, authenticated: bool, destination: ChainId, message: Self::Message);
+
+    fn message_origin_chain_id(&mut self) -> Option<ChainId>;
+    fn require_message_origin_chain_id(&mut self) -> Result<ChainId, Self::Error>;
+
+    fn application_parameters(&mut self) -> Self::Parameters;
+
+    fn call_application<A: ContractAbi + Send>(
+        &mut self,
+        authenticated: bool,
+        application: ApplicationId<A>,
+        call: &A::Operation,
+    ) -> A::Response;
+}
@@ -0,0 +1,11 @@
+use linera_sdk::linera_base_types::{ChainId, Timestamp};
+
+/// Read-only runtime available to service queries
+pub trait ServiceRuntimeContext: Send + Sync {
+    type Parameters;
+
+    fn chain_id(&self) -> ChainId;
+    fn system_time(&self) -> Timestamp;
+    fn application_creator_chain_id(&self) -> ChainId;
+    fn application_parameters(&self) -> Self::Parameters;
+}
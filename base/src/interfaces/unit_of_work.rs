@@ -0,0 +1,12 @@
+use linera_sdk::views::ViewError;
+
+/// Groups the state writes of one handler so they are kept or discarded together.
+///
+/// `begin` checkpoints the staged state, `rollback` restores the checkpoint so writes
+/// staged before the unit (e.g. by an earlier call in the same transaction) are kept.
+/// Side effects of the handler are only released once `commit` returns.
+pub trait UnitOfWork {
+    fn begin(&mut self) -> Result<(), ViewError>;
+    fn commit(&mut self);
+    fn rollback(&mut self);
+}
@@ -1 +1,4 @@
+pub mod handler;
+pub mod interfaces;
+pub mod runtime;
 pub mod types;
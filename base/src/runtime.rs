@@ -0,0 +1,5 @@
+pub mod access_control;
+pub mod contract;
+pub mod errors;
+pub mod service;
+pub mod unit_of_work;
@@ -0,0 +1,90 @@
+use std::cell::RefCell;
+
+use super::errors::RuntimeError;
+use crate::{
+    interfaces::access_control::{AccessControl, RoleContext, RoleStore},
+    types::Role,
+};
+use async_trait::async_trait;
+use linera_sdk::linera_base_types::AccountOwner;
+
+async fn has_role<S: RoleStore>(
+    store: &RefCell<S>,
+    owner: AccountOwner,
+    role: Role,
+) -> Result<bool, RuntimeError> {
+    let roles = store.borrow().roles(owner).await?;
+    Ok(roles.contains(&role) || roles.contains(&Role::Admin))
+}
+
+/// Takes `role` from `owner`, the application always keeps an admin
+async fn remove_role<S: RoleStore>(
+    store: &RefCell<S>,
+    owner: AccountOwner,
+    role: Role,
+) -> Result<(), RuntimeError> {
+    let mut roles = store.borrow().roles(owner).await?;
+    if role == Role::Admin && roles.contains(&Role::Admin) {
+        let admins = store.borrow().holders(Role::Admin).await?;
+        if admins.iter().all(|admin| *admin == owner) {
+            return Err(RuntimeError::LastAdmin { owner });
+        }
+    }
+
+    roles.retain(|granted| *granted != role);
+    Ok(store.borrow_mut().set_roles(owner, roles)?)
+}
+
+#[async_trait(?Send)]
+impl<T: RoleContext> AccessControl for T {
+    type Error = RuntimeError;
+
+    fn only_application_creator(&mut self) -> Result<(), RuntimeError> {
+        let chain_id = self.chain_id();
+        let creator_chain_id = self.application_creator_chain_id();
+
+        (chain_id == creator_chain_id)
+            .then_some(())
+            .ok_or(RuntimeError::NotApplicationCreator {
+                chain_id,
+                creator_chain_id,
+            })
+    }
+
+    async fn only_role(&mut self, role: Role) -> Result<AccountOwner, RuntimeError> {
+        let caller = self.authenticated_caller_id().map(AccountOwner::from);
+        let signer = self.authenticated_signer();
+        let store = self.role_store();
+
+        let owners = [caller, signer].into_iter().flatten().collect::<Vec<_>>();
+        for owner in &owners {
+            if has_role(&store, *owner, role).await? {
+                return Ok(*owner);
+            }
+        }
+        Err(RuntimeError::MissingRole { role, owners })
+    }
+
+    async fn grant_role(&mut self, owner: AccountOwner, role: Role) -> Result<(), RuntimeError> {
+        self.only_role(Role::Admin).await?;
+        let store = self.role_store();
+
+        let mut roles = store.borrow().roles(owner).await?;
+        if !roles.contains(&role) {
+            roles.push(role);
+        }
+        Ok(store.borrow_mut().set_roles(owner, roles)?)
+    }
+
+    async fn revoke_role(&mut self, owner: AccountOwner, role: Role) -> Result<(), RuntimeError> {
+        self.only_role(Role::Admin).await?;
+        remove_role(&self.role_store(), owner, role).await
+    }
+
+    async fn renounce_role(&mut self, role: Role) -> Result<(), RuntimeError> {
+        let owner = self
+            .authenticated_signer()
+            .ok_or(RuntimeError::InvalidAuthenticatedSigner)?;
+        remove_role(&self.role_store(), owner, role).await
+    }
+}
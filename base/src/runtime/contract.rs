@@ -0,0 +1,104 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::errors::RuntimeError;
+use crate::interfaces::{
+    access_control::{RoleContext, RoleStore},
+    runtime::{base::BaseRuntimeContext, contract::ContractRuntimeContext},
+};
+use linera_sdk::{
+    abi::ContractAbi,
+    linera_base_types::{AccountOwner, ApplicationId, ChainId, Timestamp},
+    Contract, ContractRuntime,
+};
+
+/// Contract runtime of the handlers, `roles` only has to be a `RoleStore` for the
+/// applications which check roles through `AccessControl`, see `RoleContext`
+pub struct ContractRuntimeAdapter<T: Contract, S> {
+    runtime: Rc<RefCell<ContractRuntime<T>>>,
+    roles: Rc<RefCell<S>>,
+}
+
+impl<T: Contract, S> ContractRuntimeAdapter<T, S> {
+    pub fn new(runtime: Rc<RefCell<ContractRuntime<T>>>, roles: Rc<RefCell<S>>) -> Self {
+        Self { runtime, roles }
+    }
+}
+
+impl<T: Contract, S> BaseRuntimeContext for ContractRuntimeAdapter<T, S> {
+    fn chain_id(&mut self) -> ChainId {
+        self.runtime.borrow_mut().chain_id()
+    }
+
+    fn system_time(&mut self) -> Timestamp {
+        self.runtime.borrow_mut().system_time()
+    }
+
+    fn application_creator_chain_id(&mut self) -> ChainId {
+        self.runtime.borrow_mut().application_creator_chain_id()
+    }
+}
+
+impl<T: Contract, S> ContractRuntimeContext for ContractRuntimeAdapter<T, S> {
+    type Error = RuntimeError;
+    type Message = T::Message;
+    type Parameters = T::Parameters;
+
+    fn authenticated_signer(&mut self) -> Option<AccountOwner> {
+        self.runtime.borrow_mut().authenticated_signer()
+    }
+
+    fn require_authenticated_signer(&mut self) -> Result<AccountOwner, RuntimeError> {
+        self.runtime
+            .borrow_mut()
+            .authenticated_signer()
+            .ok_or(RuntimeError::InvalidAuthenticatedSigner)
+    }
+
+    fn authenticated_caller_id(&mut self) -> Option<ApplicationId> {
+        self.runtime.borrow_mut().authenticated_caller_id()
+    }
+
+    fn send_message(&mut self, authenticated: bool, destination: ChainId, message: T::Message) {
+        let mut runtime = self.runtime.borrow_mut();
+        let message = runtime.prepare_message(message).with_tracking();
+        if authenticated {
+            message.with_authentication().send_to(destination)
+        } else {
+            message.send_to(destination)
+        }
+    }
+
+    fn message_origin_chain_id(&mut self) -> Option<ChainId> {
+        self.runtime.borrow_mut().message_origin_chain_id()
+    }
+
+    fn require_message_origin_chain_id(&mut self) -> Result<ChainId, RuntimeError> {
+        self.runtime
+            .borrow_mut()
+            .message_origin_chain_id()
+            .ok_or(RuntimeError::InvalidMessageOriginChainId)
+    }
+
+    fn application_parameters(&mut self) -> T::Parameters {
+        self.runtime.borrow_mut().application_parameters()
+    }
+
+    fn call_application<A: ContractAbi + Send>(
+        &mut self,
+        authenticated: bool,
+        application: ApplicationId<A>,
+        call: &A::Operation,
+    ) -> A::Response {
+        self.runtime
+            .borrow_mut()
+            .call_application(authenticated, application, call)
+    }
+}
+
+impl<T: Contract, S: RoleStore> RoleContext for ContractRuntimeAdapter<T, S> {
+    type Roles = S;
+
+    fn role_store(&self) -> Rc<RefCell<S>> {
+        self.roles.clone()
+    }
+}
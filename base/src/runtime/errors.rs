@@ -0,0 +1,49 @@
+use crate::types::Role;
+use linera_sdk::{
+    linera_base_types::{AccountOwner, AccountPermissionError, ChainId},
+    views::ViewError,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Deserialize, Error, Serialize)]
+pub enum RuntimeError {
+    #[error("Account permission error: {0}")]
+    AccountPermission(String),
+
+    #[error("Invalid message origin chain id")]
+    InvalidMessageOriginChainId,
+
+    #[error("Invalid authenticated signer")]
+    InvalidAuthenticatedSigner,
+
+    #[error("Chain {chain_id} is not the application creator chain {creator_chain_id}")]
+    NotApplicationCreator {
+        chain_id: ChainId,
+        creator_chain_id: ChainId,
+    },
+
+    #[error("None of the authenticated accounts {owners:?} holds the {role:?} role")]
+    MissingRole {
+        role: Role,
+        owners: Vec<AccountOwner>,
+    },
+
+    #[error("{owner} is the last admin and can't lose the admin role")]
+    LastAdmin { owner: AccountOwner },
+
+    #[error("View error: {0}")]
+    ViewError(String),
+}
+
+impl From<AccountPermissionError> for RuntimeError {
+    fn from(err: AccountPermissionError) -> Self {
+        Self::AccountPermission(err.to_string())
+    }
+}
+
+impl From<ViewError> for RuntimeError {
+    fn from(err: ViewError) -> Self {
+        Self::ViewError(err.to_string())
+    }
+}
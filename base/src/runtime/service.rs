@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::interfaces::runtime::service::ServiceRuntimeContext;
+use linera_sdk::{
+    linera_base_types::{ChainId, Timestamp},
+    Service, ServiceRuntime,
+};
+
+pub struct ServiceRuntimeAdapter<T: Service> {
+    runtime: Arc<ServiceRuntime<T>>,
+}
+
+impl<T: Service> ServiceRuntimeAdapter<T> {
+    pub fn new(runtime: Arc<ServiceRuntime<T>>) -> Self {
+        Self { runtime }
+    }
+}
+
+impl<T: Service> ServiceRuntimeContext for ServiceRuntimeAdapter<T>
+where
+    ServiceRuntime<T>: Send + Sync,
+{
+    type Parameters = T::Parameters;
+
+    fn chain_id(&self) -> ChainId {
+        self.runtime.chain_id()
+    }
+
+    fn system_time(&self) -> Timestamp {
+        self.runtime.system_time()
+    }
+
+    fn application_creator_chain_id(&self) -> ChainId {
+        self.runtime.application_creator_chain_id()
+    }
+
+    fn application_parameters(&self) -> T::Parameters {
+        self.runtime.application_parameters()
+    }
+}
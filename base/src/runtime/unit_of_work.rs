@@ -0,0 +1,37 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::interfaces::unit_of_work::UnitOfWork;
+use linera_sdk::views::{linera_views::views::ClonableView, ViewError};
+
+/// Unit of work over an application state shared with its handlers
+pub struct ViewUnitOfWork<V> {
+    state: Rc<RefCell<V>>,
+    /// Staged state at `begin` of the current unit of work
+    checkpoint: Option<V>,
+}
+
+impl<V> ViewUnitOfWork<V> {
+    pub fn new(state: Rc<RefCell<V>>) -> Self {
+        Self {
+            state,
+            checkpoint: None,
+        }
+    }
+}
+
+impl<V: ClonableView> UnitOfWork for ViewUnitOfWork<V> {
+    fn begin(&mut self) -> Result<(), ViewError> {
+        self.checkpoint = Some(self.state.borrow_mut().clone_unchecked()?);
+        Ok(())
+    }
+
+    fn commit(&mut self) {
+        self.checkpoint = None;
+    }
+
+    fn rollback(&mut self) {
+        if let Some(checkpoint) = self.checkpoint.take() {
+            *self.state.borrow_mut() = checkpoint;
+        }
+    }
+}
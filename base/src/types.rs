@@ -16,3 +16,11 @@ pub struct Candidate {
     pub owner: AccountOwner,
     pub state: CandidateState,
 }
+
+#[derive(Clone, Deserialize, Serialize, Debug, Enum, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Role {
+    /// Grants and revokes roles, and passes every role check
+    Admin,
+    Operator,
+    Caller,
+}
@@ -0,0 +1,293 @@
+//! Role checks and changes of `AccessControl` against an in-memory role store.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use async_trait::async_trait;
+use base::{
+    interfaces::{
+        access_control::{AccessControl, RoleContext, RoleStore},
+        runtime::{base::BaseRuntimeContext, contract::ContractRuntimeContext},
+    },
+    runtime::errors::RuntimeError,
+    types::Role,
+};
+use linera_sdk::{
+    abi::ContractAbi,
+    linera_base_types::{AccountOwner, ApplicationId, ChainId, CryptoHash, Timestamp},
+    views::ViewError,
+};
+
+fn owner(name: &str) -> AccountOwner {
+    AccountOwner::from(CryptoHash::test_hash(name))
+}
+
+fn chain(name: &str) -> ChainId {
+    ChainId(CryptoHash::test_hash(name))
+}
+
+#[derive(Default)]
+struct MemoryRoles(BTreeMap<AccountOwner, Vec<Role>>);
+
+#[async_trait(?Send)]
+impl RoleStore for MemoryRoles {
+    async fn roles(&self, owner: AccountOwner) -> Result<Vec<Role>, ViewError> {
+        Ok(self.0.get(&owner).cloned().unwrap_or_default())
+    }
+
+    fn set_roles(&mut self, owner: AccountOwner, roles: Vec<Role>) -> Result<(), ViewError> {
+        match roles.is_empty() {
+            true => self.0.remove(&owner),
+            false => self.0.insert(owner, roles),
+        };
+        Ok(())
+    }
+
+    async fn holders(&self, role: Role) -> Result<Vec<AccountOwner>, ViewError> {
+        Ok(self
+            .0
+            .iter()
+            .filter(|(_, roles)| roles.contains(&role))
+            .map(|(owner, _)| *owner)
+            .collect())
+    }
+}
+
+/// Runtime on the creator chain, signed by `signer`
+struct StubRuntime {
+    signer: Option<AccountOwner>,
+    chain_id: ChainId,
+    roles: Rc<RefCell<MemoryRoles>>,
+}
+
+impl StubRuntime {
+    /// `admin` holds the admin role, as after instantiation
+    fn new(admin: AccountOwner) -> Self {
+        let mut roles = MemoryRoles::default();
+        roles.0.insert(admin, vec![Role::Admin]);
+        Self {
+            signer: Some(admin),
+            chain_id: chain("creator"),
+            roles: Rc::new(RefCell::new(roles)),
+        }
+    }
+
+    fn roles_of(&self, owner: AccountOwner) -> Vec<Role> {
+        self.roles
+            .borrow()
+            .0
+            .get(&owner)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+impl BaseRuntimeContext for StubRuntime {
+    fn chain_id(&mut self) -> ChainId {
+        self.chain_id
+    }
+
+    fn system_time(&mut self) -> Timestamp {
+        Timestamp::from(0)
+    }
+
+    fn application_creator_chain_id(&mut self) -> ChainId {
+        chain("creator")
+    }
+}
+
+impl ContractRuntimeContext for StubRuntime {
+    type Error = RuntimeError;
+    type Message = ();
+    type Parameters = ();
+
+    fn authenticated_signer(&mut self) -> Option<AccountOwner> {
+        self.signer
+    }
+
+    fn require_authenticated_signer(&mut self) -> Result<AccountOwner, RuntimeError> {
+        self.signer.ok_or(RuntimeError::InvalidAuthenticatedSigner)
+    }
+
+    fn authenticated_caller_id(&mut self) -> Option<ApplicationId> {
+        None
+    }
+
+    fn send_message(&mut self, _authenticated: bool, _destination: ChainId, _message: ()) {}
+
+    fn message_origin_chain_id(&mut self) -> Option<ChainId> {
+        None
+    }
+
+    fn require_message_origin_chain_id(&mut self) -> Result<ChainId, RuntimeError> {
+        Err(RuntimeError::InvalidMessageOriginChainId)
+    }
+
+    fn application_parameters(&mut self) {}
+
+    fn call_application<A: ContractAbi + Send>(
+        &mut self,
+        _authenticated: bool,
+        _application: ApplicationId<A>,
+        _call: &A::Operation,
+    ) -> A::Response {
+        unreachable!("Role checks don't call applications")
+    }
+}
+
+impl RoleContext for StubRuntime {
+    type Roles = MemoryRoles;
+
+    fn role_store(&self) -> Rc<RefCell<MemoryRoles>> {
+        self.roles.clone()
+    }
+}
+
+#[test]
+fn only_application_creator_rejects_other_chains() {
+    let mut runtime = StubRuntime::new(owner("admin"));
+    runtime
+        .only_application_creator()
+        .expect("Failed on the creator chain");
+
+    runtime.chain_id = chain("user");
+    assert!(matches!(
+        runtime.only_application_creator(),
+        Err(RuntimeError::NotApplicationCreator { chain_id, .. }) if chain_id == chain("user")
+    ));
+}
+
+#[tokio::test]
+async fn only_role_accepts_the_holder_and_any_admin() {
+    let (admin, operator) = (owner("admin"), owner("operator"));
+    let mut runtime = StubRuntime::new(admin);
+    runtime
+        .grant_role(operator, Role::Operator)
+        .await
+        .expect("Failed to grant operator");
+
+    // Admin passes every role check
+    assert_eq!(
+        runtime
+            .only_role(Role::Operator)
+            .await
+            .expect("Admin rejected"),
+        admin
+    );
+
+    runtime.signer = Some(operator);
+    assert_eq!(
+        runtime
+            .only_role(Role::Operator)
+            .await
+            .expect("Operator rejected"),
+        operator
+    );
+    assert!(matches!(
+        runtime.only_role(Role::Admin).await,
+        Err(RuntimeError::MissingRole { role: Role::Admin, owners }) if owners == vec![operator]
+    ));
+
+    runtime.signer = None;
+    assert!(matches!(
+        runtime.only_role(Role::Operator).await,
+        Err(RuntimeError::MissingRole { owners, .. }) if owners.is_empty()
+    ));
+}
+
+#[tokio::test]
+async fn grant_role_requires_an_admin_and_keeps_roles_unique() {
+    let (admin, operator) = (owner("admin"), owner("operator"));
+    let mut runtime = StubRuntime::new(admin);
+
+    runtime.signer = Some(operator);
+    assert!(matches!(
+        runtime.grant_role(operator, Role::Operator).await,
+        Err(RuntimeError::MissingRole {
+            role: Role::Admin,
+            ..
+        })
+    ));
+    assert!(runtime.roles_of(operator).is_empty());
+
+    runtime.signer = Some(admin);
+    for _ in 0..2 {
+        runtime
+            .grant_role(operator, Role::Operator)
+            .await
+            .expect("Failed to grant operator");
+    }
+    assert_eq!(runtime.roles_of(operator), vec![Role::Operator]);
+}
+
+#[tokio::test]
+async fn revoke_role_requires_an_admin_and_keeps_the_last_admin() {
+    let (admin, operator) = (owner("admin"), owner("operator"));
+    let mut runtime = StubRuntime::new(admin);
+    runtime
+        .grant_role(operator, Role::Operator)
+        .await
+        .expect("Failed to grant operator");
+
+    runtime.signer = Some(operator);
+    assert!(matches!(
+        runtime.revoke_role(admin, Role::Admin).await,
+        Err(RuntimeError::MissingRole {
+            role: Role::Admin,
+            ..
+        })
+    ));
+
+    runtime.signer = Some(admin);
+    assert!(matches!(
+        runtime.revoke_role(admin, Role::Admin).await,
+        Err(RuntimeError::LastAdmin { owner }) if owner == admin
+    ));
+    assert_eq!(runtime.roles_of(admin), vec![Role::Admin]);
+
+    runtime
+        .revoke_role(operator, Role::Operator)
+        .await
+        .expect("Failed to revoke operator");
+    assert!(runtime.roles_of(operator).is_empty());
+
+    // With a second admin the first one can go
+    runtime
+        .grant_role(operator, Role::Admin)
+        .await
+        .expect("Failed to grant admin");
+    runtime
+        .revoke_role(admin, Role::Admin)
+        .await
+        .expect("Failed to revoke admin");
+    assert!(runtime.roles_of(admin).is_empty());
+}
+
+#[tokio::test]
+async fn renounce_role_keeps_the_last_admin() {
+    let (admin, other) = (owner("admin"), owner("other"));
+    let mut runtime = StubRuntime::new(admin);
+
+    assert!(matches!(
+        runtime.renounce_role(Role::Admin).await,
+        Err(RuntimeError::LastAdmin { owner }) if owner == admin
+    ));
+    assert_eq!(runtime.roles_of(admin), vec![Role::Admin]);
+
+    runtime
+        .grant_role(other, Role::Admin)
+        .await
+        .expect("Failed to grant admin");
+    runtime
+        .renounce_role(Role::Admin)
+        .await
+        .expect("Failed to renounce admin");
+    assert!(runtime.roles_of(admin).is_empty());
+
+    runtime.signer = None;
+    assert!(matches!(
+        runtime.renounce_role(Role::Admin).await,
+        Err(RuntimeError::InvalidAuthenticatedSigner)
+    ));
+}
@@ -0,0 +1,41 @@
+//! Layer errors carried by `HandlerError` keep their variant and context.
+
+use base::{handler::errors::HandlerError, runtime::errors::RuntimeError, types::Role};
+use linera_sdk::linera_base_types::{AccountOwner, CryptoHash};
+
+#[test]
+fn permission_error_decodes_to_its_runtime_error() {
+    let owner = AccountOwner::from(CryptoHash::test_hash("owner"));
+    let err = HandlerError::from(RuntimeError::MissingRole {
+        role: Role::Admin,
+        owners: vec![owner],
+    });
+
+    let HandlerError::Permission(layer) = &err else {
+        panic!("Expected a permission error, got {err:?}");
+    };
+    assert_eq!(layer.code, "MissingRole");
+    match layer.decode::<RuntimeError>() {
+        Some(RuntimeError::MissingRole { role, owners }) => {
+            assert_eq!(role, Role::Admin);
+            assert_eq!(owners, vec![owner]);
+        }
+        other => panic!("Unexpected decoded error {other:?}"),
+    }
+}
+
+#[test]
+fn layer_error_survives_bcs() {
+    let err = HandlerError::from(RuntimeError::InvalidAuthenticatedSigner);
+
+    let bytes = bcs::to_bytes(&err).expect("Failed to serialize");
+    let decoded: HandlerError = bcs::from_bytes(&bytes).expect("Failed to deserialize");
+
+    assert_eq!(decoded, err);
+    let layer = decoded.layer().expect("Missing layer error");
+    assert_eq!(layer.code, "InvalidAuthenticatedSigner");
+    assert!(matches!(
+        layer.decode::<RuntimeError>(),
+        Some(RuntimeError::InvalidAuthenticatedSigner)
+    ));
+}
@@ -0,0 +1,58 @@
+use super::CreditContract;
+
+use base::{
+    handler::{errors::HandlerError, interfaces::HandlerFactory as _, types::OutgoingMessage},
+    interfaces::runtime::contract::ContractRuntimeContext,
+    runtime::contract::ContractRuntimeAdapter,
+};
+use credit_v1::{
+    contract_inner::handlers::{types::HandlerOutcome, HandlerFactory},
+    state::adapter::StateAdapter,
+    Message, Operation,
+};
+
+impl CreditContract {
+    /// credit-v1 answers operations with no response, a failed operation fails its block
+    pub async fn on_op(&mut self, op: &Operation) {
+        if let Err(err) = self.execute(Some(op), None).await {
+            panic!("Failed OP: {:?}: {err}", op);
+        }
+    }
+
+    pub async fn on_message(&mut self, msg: &Message) {
+        // A rejected message comes back to its sender, where there's nothing to redo
+        if self.runtime.borrow_mut().message_is_bouncing() == Some(true) {
+            log::warn!("Bounced MSG {:?}", msg);
+            return;
+        }
+        if let Err(err) = self.execute(None, Some(msg)).await {
+            panic!("Failed MSG: {:?}: {err}", msg);
+        }
+    }
+
+    /// Runs the handler, then sends the messages it queued
+    async fn execute(
+        &mut self,
+        op: Option<&Operation>,
+        msg: Option<&Message>,
+    ) -> Result<HandlerOutcome, HandlerError> {
+        let runtime_context = ContractRuntimeAdapter::new(self.runtime.clone(), self.state.clone());
+        let state_adapter = StateAdapter::new(self.state.clone());
+
+        let mut outcome = HandlerFactory::new(runtime_context, state_adapter, op, msg)?
+            .handle()
+            .await?;
+
+        let mut runtime_context =
+            ContractRuntimeAdapter::new(self.runtime.clone(), self.state.clone());
+        for OutgoingMessage {
+            authenticated,
+            destination,
+            message,
+        } in outcome.messages.drain(..)
+        {
+            runtime_context.send_message(authenticated, destination, message);
+        }
+        Ok(outcome)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    CreditError,
+};
+use base::handler::errors::HandlerError;
+
+/// Fails unless the operation runs on the creator chain and is signed by the admin
+pub fn ensure_admin<R: ContractRuntimeContext, S: StateInterface>(
+    runtime: &mut R,
+    state: &S,
+) -> Result<(), HandlerError> {
+    if runtime.chain_id() != runtime.application_creator_chain_id() {
+        return Err(HandlerError::permission(CreditError::OperationNotAllowed));
+    }
+    let signer = runtime
+        .require_authenticated_signer()
+        .map_err(HandlerError::runtime)?;
+    if state.admin() != Some(signer) {
+        return Err(HandlerError::permission(CreditError::InvalidSigner));
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+pub mod message;
+pub mod operation;
+pub mod types;
+
+use crate::interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface};
+use crate::{Message, Operation};
+use base_macros::env_handler;
+use message::{
+    instantiation_argument::InstantiationArgumentMessageHandler,
+    liquidate::LiquidateMessageHandler, request_subscribe::RequestSubscribeMessageHandler,
+    reward::RewardMessageHandler, set_reward_callers::SetRewardCallersMessageHandler,
+    set_transfer_callers::SetTransferCallersMessageHandler, transfer::TransferMessageHandler,
+    transfer_ext::TransferExtMessageHandler,
+};
+use operation::{
+    abort_export::AbortExportHandler, export::ExportHandler, liquidate::LiquidateHandler,
+    request_subscribe::RequestSubscribeHandler, reward::RewardHandler,
+    set_reward_callers::SetRewardCallersHandler, set_transfer_callers::SetTransferCallersHandler,
+    transfer::TransferHandler, transfer_ext::TransferExtHandler,
+};
+
+#[env_handler(
+    runtime = ContractRuntimeContext,
+    state = StateInterface,
+    operation = Operation {
+        Liquidate => LiquidateHandler,
+        Transfer => TransferHandler,
+        TransferExt => TransferExtHandler,
+        SetRewardCallers => SetRewardCallersHandler,
+        SetTransferCallers => SetTransferCallersHandler,
+        RequestSubscribe => RequestSubscribeHandler,
+        Reward => RewardHandler,
+        Export => ExportHandler,
+        AbortExport => AbortExportHandler,
+    },
+    message = Message {
+        InstantiationArgument => InstantiationArgumentMessageHandler,
+        Liquidate => LiquidateMessageHandler,
+        Reward => RewardMessageHandler,
+        Transfer => TransferMessageHandler,
+        TransferExt => TransferExtMessageHandler,
+        SetRewardCallers => SetRewardCallersMessageHandler,
+        SetTransferCallers => SetTransferCallersMessageHandler,
+        RequestSubscribe => RequestSubscribeMessageHandler,
+    },
+)]
+pub struct HandlerFactory;
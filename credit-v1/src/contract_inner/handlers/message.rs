@@ -0,0 +1,8 @@
+pub mod instantiation_argument;
+pub mod liquidate;
+pub mod request_subscribe;
+pub mod reward;
+pub mod set_reward_callers;
+pub mod set_transfer_callers;
+pub mod transfer;
+pub mod transfer_ext;
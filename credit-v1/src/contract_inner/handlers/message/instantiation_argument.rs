@@ -0,0 +1,28 @@
+use crate::{
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    InstantiationArgument, Message,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Message::InstantiationArgument)]
+#[handler]
+pub struct InstantiationArgumentMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    argument: InstantiationArgument,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for InstantiationArgumentMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        self.state.instantiate(self.argument.clone().into());
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    Message,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Message::Liquidate)]
+#[handler]
+pub struct LiquidateMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for LiquidateMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let now = self.runtime.system_time();
+        self.state
+            .liquidate(now)
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    Message,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Message::RequestSubscribe)]
+#[handler]
+pub struct RequestSubscribeMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    _state: S,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for RequestSubscribeMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        // The subscribe message must be from another chain
+        Ok(HandlerOutcome::default())
+    }
+}
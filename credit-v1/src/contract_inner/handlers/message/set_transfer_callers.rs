@@ -0,0 +1,40 @@
+use crate::{
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    CreditError, Message,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::ApplicationId;
+
+#[operation_handler(Message::SetTransferCallers)]
+#[handler]
+pub struct SetTransferCallersMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    application_ids: Vec<ApplicationId>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for SetTransferCallersMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let origin_chain_id = self
+            .runtime
+            .require_message_origin_chain_id()
+            .map_err(HandlerError::runtime)?;
+        if origin_chain_id != self.runtime.application_creator_chain_id() {
+            return Err(HandlerError::permission(CreditError::OperationNotAllowed));
+        }
+
+        self.state
+            .set_transfer_callers(self.application_ids.clone())
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    Message,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount};
+
+#[operation_handler(Message::TransferExt)]
+#[handler]
+pub struct TransferExtMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    to: AccountOwner,
+    amount: Amount,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for TransferExtMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let from = self
+            .runtime
+            .require_authenticated_signer()
+            .map_err(HandlerError::runtime)?;
+        let now = self.runtime.system_time();
+        self.state
+            .transfer(from, self.to, self.amount, now)
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod abort_export;
+pub mod export;
+pub mod liquidate;
+pub mod request_subscribe;
+pub mod reward;
+pub mod set_reward_callers;
+pub mod set_transfer_callers;
+pub mod transfer;
+pub mod transfer_ext;
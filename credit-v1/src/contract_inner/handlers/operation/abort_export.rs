@@ -0,0 +1,26 @@
+use crate::{
+    contract_inner::{admin::ensure_admin, handlers::types::HandlerOutcome},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    Message, Operation,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Operation::AbortExport)]
+#[handler]
+pub struct AbortExportHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for AbortExportHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        ensure_admin(&mut self.runtime, &self.state)?;
+
+        self.state.abort_export().map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
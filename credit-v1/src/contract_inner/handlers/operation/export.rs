@@ -0,0 +1,52 @@
+use crate::{
+    contract_inner::{admin::ensure_admin, handlers::types::HandlerOutcome},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    CreditError, Message, Operation,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::ApplicationId;
+
+/// Exports one page of accounts into the credit-v2 application on this chain.
+///
+/// The import is a synchronous call rather than a message so a page rejected by
+/// credit-v2 fails this operation and the block with it, the caller retries the
+/// same page instead of losing it. Both applications must share the creator chain.
+/// The first page pins `application_id` and stops the v1 accounts from changing, so
+/// the offsets of the following pages keep walking the same owners and the totals of
+/// the last page match the exported accounts. Only the admin may export, an export
+/// to the wrong application is undone with `AbortExport`.
+#[operation_handler(Operation::Export)]
+#[handler]
+pub struct ExportHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    application_id: ApplicationId,
+    offset: u32,
+    limit: u32,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for ExportHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        ensure_admin(&mut self.runtime, &self.state)?;
+
+        let records = self
+            .state
+            .export_page(self.application_id, self.offset, self.limit)
+            .await
+            .map_err(HandlerError::state)?;
+        self.runtime
+            .call_application(
+                true,
+                self.application_id.with_abi::<credit_v2::abi::CreditAbi>(),
+                &credit_v2::abi::Operation::ImportState { records },
+            )
+            .map_err(|err| HandlerError::state(CreditError::ImportFailed(err.to_string())))?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
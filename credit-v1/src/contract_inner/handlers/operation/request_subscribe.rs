@@ -0,0 +1,28 @@
+use crate::{
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    Message, Operation,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Operation::RequestSubscribe)]
+#[handler]
+pub struct RequestSubscribeHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    _state: S,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for RequestSubscribeHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let destination = self.runtime.application_creator_chain_id();
+        let mut outcome = HandlerOutcome::default();
+        outcome.send_message(true, destination, Message::RequestSubscribe);
+
+        Ok(outcome)
+    }
+}
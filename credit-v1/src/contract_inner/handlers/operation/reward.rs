@@ -0,0 +1,38 @@
+use crate::{
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    Message, Operation,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount};
+
+#[operation_handler(Operation::Reward)]
+#[handler]
+pub struct RewardHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    _state: S,
+
+    owner: AccountOwner,
+    amount: Amount,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for RewardHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let destination = self.runtime.application_creator_chain_id();
+        let mut outcome = HandlerOutcome::default();
+        outcome.send_message(
+            true,
+            destination,
+            Message::Reward {
+                owner: self.owner,
+                amount: self.amount,
+            },
+        );
+
+        Ok(outcome)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    CreditError, Message, Operation,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::ApplicationId;
+
+#[operation_handler(Operation::SetRewardCallers)]
+#[handler]
+pub struct SetRewardCallersHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    _state: S,
+
+    application_ids: Vec<ApplicationId>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for SetRewardCallersHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        if self.runtime.chain_id() != self.runtime.application_creator_chain_id() {
+            return Err(HandlerError::permission(CreditError::OperationNotAllowed));
+        }
+
+        let destination = self.runtime.application_creator_chain_id();
+        let mut outcome = HandlerOutcome::default();
+        outcome.send_message(
+            true,
+            destination,
+            Message::SetRewardCallers {
+                application_ids: self.application_ids.clone(),
+            },
+        );
+
+        Ok(outcome)
+    }
+}
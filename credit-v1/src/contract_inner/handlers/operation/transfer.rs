@@ -0,0 +1,40 @@
+use crate::{
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    Message, Operation,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount};
+
+#[operation_handler(Operation::Transfer)]
+#[handler]
+pub struct TransferHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    _state: S,
+
+    from: AccountOwner,
+    to: AccountOwner,
+    amount: Amount,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for TransferHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let destination = self.runtime.application_creator_chain_id();
+        let mut outcome = HandlerOutcome::default();
+        outcome.send_message(
+            true,
+            destination,
+            Message::Transfer {
+                from: self.from,
+                to: self.to,
+                amount: self.amount,
+            },
+        );
+
+        Ok(outcome)
+    }
+}
@@ -0,0 +1,3 @@
+use crate::Message;
+
+pub type HandlerOutcome = base::handler::types::HandlerOutcome<Message>;
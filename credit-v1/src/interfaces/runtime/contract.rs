@@ -0,0 +1,13 @@
+use crate::Message;
+use base::interfaces::runtime::contract::ContractRuntimeContext as BaseContractRuntimeContext;
+
+/// Contract runtime bound to the credit-v1 message type, the application has no parameters
+pub trait ContractRuntimeContext:
+    BaseContractRuntimeContext<Message = Message, Parameters = ()>
+{
+}
+
+impl<T: BaseContractRuntimeContext<Message = Message, Parameters = ()>> ContractRuntimeContext
+    for T
+{
+}
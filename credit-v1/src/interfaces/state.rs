@@ -0,0 +1,29 @@
+use crate::CreditError;
+use async_trait::async_trait;
+use credit_v2::{interfaces::state::LedgerInterface, types::StateRecord};
+use linera_sdk::linera_base_types::{AccountOwner, ApplicationId};
+
+/// The ledger shared with credit-v2 and what only credit-v1 has
+#[async_trait(?Send)]
+pub trait StateInterface: LedgerInterface<Error = CreditError> {
+    fn set_reward_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), CreditError>;
+    fn set_transfer_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), CreditError>;
+    fn admin(&self) -> Option<AccountOwner>;
+    /// Records of the export page at `offset` for `application_id`. The first page pins
+    /// the target and stops the accounts from changing, the following pages are only
+    /// served to the same target while they are stopped.
+    async fn export_page(
+        &mut self,
+        application_id: ApplicationId,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<StateRecord>, CreditError>;
+    /// Lets the accounts change again, a new export starts over from the first page
+    fn abort_export(&mut self) -> Result<(), CreditError>;
+}
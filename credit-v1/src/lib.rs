@@ -2,10 +2,15 @@ use async_graphql::{Request, Response, SimpleObject};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
     linera_base_types::{AccountOwner, Amount, ApplicationId, ContractAbi, ServiceAbi, Timestamp},
+    views::ViewError,
 };
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+pub mod contract_inner;
+pub mod interfaces;
+pub mod state;
+
 pub struct CreditAbi;
 
 impl ContractAbi for CreditAbi {
@@ -31,11 +36,17 @@ pub struct AgeAmounts {
 
 impl AgeAmounts {
     pub fn sum(&self) -> Amount {
-        let mut _sum = Amount::ZERO;
         self.amounts
             .iter()
-            .for_each(|a| _sum = _sum.try_add(a.amount).unwrap());
-        _sum
+            .fold(Amount::ZERO, |sum, a| sum.saturating_add(a.amount))
+    }
+
+    /// Sum of the lots which `liquidate` at `now` would keep
+    pub fn alive_sum(&self, now: Timestamp) -> Amount {
+        self.amounts
+            .iter()
+            .filter(|a| now.micros() <= a.expired.micros())
+            .fold(Amount::ZERO, |sum, a| sum.saturating_add(a.amount))
     }
 }
 
@@ -45,6 +56,15 @@ pub struct InstantiationArgument {
     pub amount_alive_ms: u64,
 }
 
+impl From<InstantiationArgument> for credit_v2::instantiation_argument::InstantiationArgument {
+    fn from(argument: InstantiationArgument) -> Self {
+        Self {
+            initial_supply: argument.initial_supply,
+            amount_alive_ms: argument.amount_alive_ms,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, GraphQLMutationRoot)]
 pub enum Operation {
     Liquidate,
@@ -68,6 +88,14 @@ pub enum Operation {
         owner: AccountOwner,
         amount: Amount,
     },
+    /// Exports one page of accounts to a credit-v2 application created on this chain
+    Export {
+        application_id: ApplicationId,
+        offset: u32,
+        limit: u32,
+    },
+    /// Stops an export so the accounts can change again
+    AbortExport,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -99,15 +127,15 @@ pub enum Message {
 }
 
 /// An error that can occur during the contract execution.
-#[derive(Debug, Error)]
+#[derive(Debug, Deserialize, Error, Serialize)]
 pub enum CreditError {
     /// Failed to deserialize BCS bytes
-    #[error("Failed to deserialize BCS bytes")]
-    BcsError(#[from] bcs::Error),
+    #[error("Failed to deserialize BCS bytes: {0}")]
+    BcsError(String),
 
     /// Failed to deserialize JSON string
-    #[error("Failed to deserialize JSON string")]
-    JsonError(#[from] serde_json::Error),
+    #[error("Failed to deserialize JSON string: {0}")]
+    JsonError(String),
 
     #[error("NOT IMPLEMENTED")]
     NotImplemented,
@@ -130,6 +158,36 @@ pub enum CreditError {
     #[error("Invalid message id")]
     InvalidMessageId,
 
-    #[error("View error")]
-    ViewError(#[from] linera_sdk::views::ViewError),
+    #[error("Import failed: {0}")]
+    ImportFailed(String),
+
+    #[error("Accounts are being exported")]
+    Exporting,
+
+    #[error("Accounts are being exported to {0}")]
+    ExportTargetMismatch(ApplicationId),
+
+    #[error("No export in progress")]
+    NotExporting,
+
+    #[error("View error: {0}")]
+    ViewError(String),
+}
+
+impl From<bcs::Error> for CreditError {
+    fn from(err: bcs::Error) -> Self {
+        Self::BcsError(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for CreditError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::JsonError(err.to_string())
+    }
+}
+
+impl From<ViewError> for CreditError {
+    fn from(err: ViewError) -> Self {
+        Self::ViewError(err.to_string())
+    }
 }
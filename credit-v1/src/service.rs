@@ -1,10 +1,7 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-mod state;
-
-use self::state::CreditState;
 use async_graphql::{EmptySubscription, Request, Response, Schema};
-use credit_v1::Operation;
+use credit_v1::{state::CreditState, Operation};
 use linera_sdk::{
     graphql::GraphQLMutationRoot, linera_base_types::WithServiceAbi, views::View, Service,
     ServiceRuntime,
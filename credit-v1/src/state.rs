@@ -1,9 +1,10 @@
-use std::cmp::Ordering;
-
+use crate::{AgeAmounts, CreditError};
 use async_graphql::SimpleObject;
-use credit_v1::{AgeAmount, AgeAmounts, CreditError, InstantiationArgument};
+use credit_v2::types::{
+    AccountRecord, AgeAmount as ExportAgeAmount, FinalizeRecord, HeaderRecord, StateRecord,
+};
 use linera_sdk::{
-    linera_base_types::{AccountOwner, Amount, ApplicationId, Timestamp},
+    linera_base_types::{AccountOwner, Amount, ApplicationId},
     views::{linera_views, MapView, RegisterView, RootView, SetView, ViewStorageContext},
 };
 
@@ -17,217 +18,82 @@ pub struct CreditState {
     pub spendables: MapView<AccountOwner, Amount>,
     pub reward_callers: SetView<ApplicationId>,
     pub transfer_callers: SetView<ApplicationId>,
+    /// Signer of the instantiation, the only one allowed to export the accounts
+    pub admin: RegisterView<Option<AccountOwner>>,
+    /// Application the accounts are exported to, set once an export starts. Accounts
+    /// stop changing until the export is aborted so the export pages stay consistent
+    /// with each other
+    pub export_target: RegisterView<Option<ApplicationId>>,
 }
 
-#[allow(dead_code)]
 impl CreditState {
-    pub(crate) async fn initialize_credit(&mut self, mut argument: InstantiationArgument) {
-        if argument.initial_supply.eq(&Amount::ZERO) {
-            argument.initial_supply = Amount::from_tokens(100000000);
+    pub(crate) fn ensure_not_exporting(&self) -> Result<(), CreditError> {
+        match self.export_target.get() {
+            Some(_) => Err(CreditError::Exporting),
+            None => Ok(()),
         }
-        self._initial_supply.set(argument.initial_supply);
-        self._balance.set(argument.initial_supply);
-        self.amount_alive_ms.set(argument.amount_alive_ms);
     }
 
-    pub(crate) async fn instantiation_argument(
+    /// Builds the records of one export page, the first page carries the header and
+    /// callers and the last page closes the export with the totals to verify
+    pub async fn export_records(
         &self,
-    ) -> Result<InstantiationArgument, CreditError> {
-        Ok(InstantiationArgument {
-            initial_supply: *self._initial_supply.get(),
-            amount_alive_ms: *self.amount_alive_ms.get(),
-        })
-    }
-
-    pub(crate) async fn initial_supply(&self) -> Amount {
-        *self._initial_supply.get()
-    }
-
-    pub(crate) async fn balance(&self, owner: Option<AccountOwner>) -> Amount {
-        match owner {
-            Some(owner) => self.balances.get(&owner).await.unwrap().unwrap().sum(),
-            None => *self._balance.get(),
-        }
-    }
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<StateRecord>, CreditError> {
+        let mut records = Vec::new();
 
-    pub(crate) async fn reward(
-        &mut self,
-        owner: AccountOwner,
-        amount: Amount,
-        now: Timestamp,
-    ) -> Result<(), CreditError> {
-        match self.spendables.get(&owner).await {
-            Ok(Some(spendable)) => {
-                self.spendables
-                    .insert(&owner, spendable.saturating_add(amount))
-                    .unwrap();
-            }
-            _ => {
-                self.spendables.insert(&owner, amount).unwrap();
-            }
+        if offset == 0 {
+            records.push(StateRecord::Header(HeaderRecord {
+                version: 1,
+                initial_supply: *self._initial_supply.get(),
+                supply_balance: *self._balance.get(),
+                amount_alive_ms: *self.amount_alive_ms.get(),
+            }));
+            records.push(StateRecord::RewardCallers(
+                self.reward_callers.indices().await?,
+            ));
+            records.push(StateRecord::TransferCallers(
+                self.transfer_callers.indices().await?,
+            ));
         }
 
-        match self._balance.get().cmp(&amount) {
-            Ordering::Less => {
-                log::error!(
-                    "Here we should correct: supply balance {} reward amount {}",
-                    self._balance.get(),
-                    amount
-                );
-                // return Err(CreditError::InsufficientSupplyBalance)
-            }
-            _ => {}
+        let owners = self.balances.indices().await?;
+        let end = owners.len().min(offset as usize + limit as usize);
+        for owner in owners.iter().take(end).skip(offset as usize) {
+            let amounts = self.balances.get(owner).await?.unwrap_or(AgeAmounts {
+                amounts: Vec::new(),
+            });
+            records.push(StateRecord::Account(AccountRecord {
+                owner: *owner,
+                amounts: amounts
+                    .amounts
+                    .into_iter()
+                    .map(|amount| ExportAgeAmount {
+                        amount: amount.amount,
+                        expired: amount.expired,
+                    })
+                    .collect(),
+                spendable: self.spendables.get(owner).await?.unwrap_or_default(),
+            }));
         }
 
-        self._balance
-            .set(self._balance.get().saturating_sub(amount));
-
-        match self.balances.get(&owner).await {
-            Ok(Some(mut amounts)) => {
-                amounts.amounts.push(AgeAmount {
-                    amount,
-                    expired: Timestamp::from(
-                        now.micros().saturating_add(*self.amount_alive_ms.get()),
-                    ),
-                });
-                match self.balances.insert(&owner, amounts) {
-                    Ok(_) => Ok(()),
-                    Err(err) => Err(CreditError::ViewError(err)),
-                }
+        if end == owners.len() {
+            let mut total_spendable = Amount::ZERO;
+            for owner in owners.iter() {
+                let spendable = self.spendables.get(owner).await?.unwrap_or_default();
+                total_spendable = total_spendable.saturating_add(spendable);
             }
-            _ => match self.balances.insert(
-                &owner,
-                AgeAmounts {
-                    amounts: vec![AgeAmount {
-                        amount,
-                        expired: Timestamp::from(
-                            now.micros().saturating_add(*self.amount_alive_ms.get()),
-                        ),
-                    }],
-                },
-            ) {
-                Ok(_) => Ok(()),
-                Err(err) => Err(CreditError::ViewError(err)),
-            },
-        }
-    }
-
-    pub(crate) async fn liquidate(&mut self, now: Timestamp) {
-        let owners = self.balances.indices().await.unwrap();
-        for owner in owners {
-            let mut amounts = match self.balances.get(&owner).await {
-                Ok(Some(amounts)) => amounts,
-                _ => continue,
-            };
-            let mut spendable = match self.spendables.get(&owner).await {
-                Ok(Some(spendable)) => spendable,
-                _ => continue,
-            };
-            amounts.amounts.retain(|amount| {
-                let expired = now.micros() > amount.expired.micros();
-                if expired {
-                    self._balance
-                        .set(self._balance.get().saturating_add(amount.amount));
-                    spendable = spendable.saturating_sub(amount.amount);
-                }
-                !expired
-            });
-            self.spendables.insert(&owner, spendable).unwrap();
-            self.balances.insert(&owner, amounts).unwrap();
+            records.push(StateRecord::Finalize(FinalizeRecord {
+                accounts: owners.len() as u32,
+                total_spendable,
+                state_hash: None,
+            }));
         }
-    }
 
-    pub(crate) async fn set_reward_callers(&mut self, application_ids: Vec<ApplicationId>) {
-        application_ids
-            .iter()
-            .for_each(|application_id| self.reward_callers.insert(application_id).unwrap())
-    }
-
-    pub(crate) async fn set_transfer_callers(&mut self, application_ids: Vec<ApplicationId>) {
-        application_ids
-            .iter()
-            .for_each(|application_id| self.transfer_callers.insert(application_id).unwrap())
-    }
-
-    pub(crate) async fn transfer(
-        &mut self,
-        from: AccountOwner,
-        to: AccountOwner,
-        amount: Amount,
-        now: Timestamp,
-    ) -> Result<(), CreditError> {
-        match self.spendables.get(&from).await {
-            Ok(Some(spendable)) => match spendable.cmp(&amount) {
-                Ordering::Less => Err(CreditError::InsufficientAccountBalance),
-                _ => {
-                    self.spendables
-                        .insert(&from, spendable.saturating_sub(amount))?;
-                    let mut amounts = self.balances.get(&from).await.unwrap().unwrap();
-                    let mut total: Amount = Amount::ZERO;
-                    let mut remain: Option<AgeAmount> = None;
-                    amounts.amounts.retain(|_amount| {
-                        if total.ge(&amount) {
-                            return true;
-                        }
-                        total = total.saturating_add(_amount.amount);
-                        if total.ge(&amount) {
-                            match total.try_sub(amount) {
-                                Ok(result) => {
-                                    remain = Some(AgeAmount {
-                                        amount: result,
-                                        expired: Timestamp::from(
-                                            now.micros()
-                                                .saturating_add(*self.amount_alive_ms.get()),
-                                        ),
-                                    })
-                                }
-                                _ => {}
-                            }
-                            return false;
-                        }
-                        return false;
-                    });
-                    match remain {
-                        Some(result) => amounts.amounts.push(result),
-                        _ => {}
-                    }
-                    self.balances.insert(&from, amounts).unwrap();
-                    match self.balances.get(&to).await {
-                        Ok(Some(mut amounts)) => {
-                            amounts.amounts.push(AgeAmount {
-                                amount,
-                                expired: Timestamp::from(
-                                    now.micros().saturating_add(*self.amount_alive_ms.get()),
-                                ),
-                            });
-                            self.balances.insert(&to, amounts).unwrap();
-                        }
-                        _ => self
-                            .balances
-                            .insert(
-                                &to,
-                                AgeAmounts {
-                                    amounts: vec![AgeAmount {
-                                        amount,
-                                        expired: Timestamp::from(
-                                            now.micros()
-                                                .saturating_add(*self.amount_alive_ms.get()),
-                                        ),
-                                    }],
-                                },
-                            )
-                            .unwrap(),
-                    }
-                    match self.spendables.get(&to).await {
-                        Ok(Some(spendable)) => self
-                            .spendables
-                            .insert(&to, spendable.saturating_add(amount))?,
-                        _ => self.spendables.insert(&to, amount)?,
-                    }
-                    Ok(())
-                }
-            },
-            _ => return Err(CreditError::InsufficientAccountBalance),
-        }
+        Ok(records)
     }
 }
+
+pub mod adapter;
+mod state_impl;
@@ -0,0 +1,101 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{interfaces::state::StateInterface, state::CreditState, CreditError};
+use async_trait::async_trait;
+use credit_v2::{
+    instantiation_argument::InstantiationArgument, interfaces::state::LedgerInterface,
+    types::StateRecord,
+};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, Timestamp};
+
+pub struct StateAdapter {
+    state: Rc<RefCell<CreditState>>,
+}
+
+impl StateAdapter {
+    pub fn new(state: Rc<RefCell<CreditState>>) -> Self {
+        Self { state }
+    }
+}
+
+#[async_trait(?Send)]
+impl LedgerInterface for StateAdapter {
+    type Error = CreditError;
+
+    fn instantiate(&mut self, argument: InstantiationArgument) {
+        self.state.borrow_mut().instantiate(argument)
+    }
+
+    async fn alive_balance(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Amount, Self::Error> {
+        self.state.borrow().alive_balance(owner, now).await
+    }
+
+    async fn reward(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), Self::Error> {
+        self.state.borrow_mut().reward(owner, amount, now).await
+    }
+
+    async fn transfer(
+        &mut self,
+        from: AccountOwner,
+        to: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .transfer(from, to, amount, now)
+            .await
+    }
+
+    async fn liquidate(&mut self, now: Timestamp) -> Result<(), Self::Error> {
+        self.state.borrow_mut().liquidate(now).await
+    }
+}
+
+#[async_trait(?Send)]
+impl StateInterface for StateAdapter {
+    fn set_reward_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), CreditError> {
+        self.state.borrow_mut().set_reward_callers(application_ids)
+    }
+
+    fn set_transfer_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), CreditError> {
+        self.state
+            .borrow_mut()
+            .set_transfer_callers(application_ids)
+    }
+
+    fn admin(&self) -> Option<AccountOwner> {
+        self.state.borrow().admin()
+    }
+
+    async fn export_page(
+        &mut self,
+        application_id: ApplicationId,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<StateRecord>, CreditError> {
+        self.state
+            .borrow_mut()
+            .export_page(application_id, offset, limit)
+            .await
+    }
+
+    fn abort_export(&mut self) -> Result<(), CreditError> {
+        self.state.borrow_mut().abort_export()
+    }
+}
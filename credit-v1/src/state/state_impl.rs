@@ -0,0 +1,206 @@
+use std::cmp::Ordering;
+
+use super::CreditState;
+use crate::{interfaces::state::StateInterface, AgeAmount, AgeAmounts, CreditError};
+use async_trait::async_trait;
+use credit_v2::{
+    instantiation_argument::InstantiationArgument, interfaces::state::LedgerInterface,
+    types::StateRecord,
+};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, Timestamp};
+
+/// credit-v1 behind the ledger interface shared with credit-v2, the reference the
+/// differential tests run credit-v2 against
+#[async_trait(?Send)]
+impl LedgerInterface for CreditState {
+    type Error = CreditError;
+
+    fn instantiate(&mut self, mut argument: InstantiationArgument) {
+        if argument.initial_supply.eq(&Amount::ZERO) {
+            argument.initial_supply = Amount::from_tokens(100000000);
+        }
+        self._initial_supply.set(argument.initial_supply);
+        self._balance.set(argument.initial_supply);
+        self.amount_alive_ms.set(argument.amount_alive_ms);
+    }
+
+    async fn alive_balance(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Amount, CreditError> {
+        Ok(self
+            .balances
+            .get(&owner)
+            .await?
+            .map(|amounts| amounts.alive_sum(now))
+            .unwrap_or_default())
+    }
+
+    async fn reward(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), CreditError> {
+        self.ensure_not_exporting()?;
+
+        let spendable = self.spendables.get(&owner).await?.unwrap_or_default();
+        self.spendables
+            .insert(&owner, spendable.saturating_add(amount))?;
+
+        match self._balance.get().cmp(&amount) {
+            Ordering::Less => {
+                log::error!(
+                    "Here we should correct: supply balance {} reward amount {}",
+                    self._balance.get(),
+                    amount
+                );
+                // return Err(CreditError::InsufficientSupplyBalance)
+            }
+            _ => {}
+        }
+
+        self._balance
+            .set(self._balance.get().saturating_sub(amount));
+
+        let mut amounts = self.balances.get(&owner).await?.unwrap_or(AgeAmounts {
+            amounts: Vec::new(),
+        });
+        amounts.amounts.push(AgeAmount {
+            amount,
+            expired: Timestamp::from(now.micros().saturating_add(*self.amount_alive_ms.get())),
+        });
+        self.balances.insert(&owner, amounts)?;
+        Ok(())
+    }
+
+    async fn liquidate(&mut self, now: Timestamp) -> Result<(), CreditError> {
+        self.ensure_not_exporting()?;
+        let owners = self.balances.indices().await?;
+        for owner in owners {
+            let Some(mut amounts) = self.balances.get(&owner).await? else {
+                continue;
+            };
+            let Some(mut spendable) = self.spendables.get(&owner).await? else {
+                continue;
+            };
+            amounts.amounts.retain(|amount| {
+                let expired = now.micros() > amount.expired.micros();
+                if expired {
+                    self._balance
+                        .set(self._balance.get().saturating_add(amount.amount));
+                    spendable = spendable.saturating_sub(amount.amount);
+                }
+                !expired
+            });
+            self.spendables.insert(&owner, spendable)?;
+            self.balances.insert(&owner, amounts)?;
+        }
+        Ok(())
+    }
+
+    async fn transfer(
+        &mut self,
+        from: AccountOwner,
+        to: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), CreditError> {
+        self.ensure_not_exporting()?;
+
+        let spendable = self.spendables.get(&from).await?.unwrap_or_default();
+        if spendable < amount {
+            return Err(CreditError::InsufficientAccountBalance);
+        }
+        let Some(mut amounts) = self.balances.get(&from).await? else {
+            return Err(CreditError::InsufficientAccountBalance);
+        };
+
+        self.spendables
+            .insert(&from, spendable.saturating_sub(amount))?;
+        let expired = Timestamp::from(now.micros().saturating_add(*self.amount_alive_ms.get()));
+        let mut total: Amount = Amount::ZERO;
+        let mut remain: Option<AgeAmount> = None;
+        amounts.amounts.retain(|_amount| {
+            if total.ge(&amount) {
+                return true;
+            }
+            total = total.saturating_add(_amount.amount);
+            if total.ge(&amount) {
+                if let Ok(result) = total.try_sub(amount) {
+                    remain = Some(AgeAmount {
+                        amount: result,
+                        expired,
+                    });
+                }
+            }
+            false
+        });
+        if let Some(result) = remain {
+            amounts.amounts.push(result);
+        }
+        self.balances.insert(&from, amounts)?;
+
+        let mut amounts = self.balances.get(&to).await?.unwrap_or(AgeAmounts {
+            amounts: Vec::new(),
+        });
+        amounts.amounts.push(AgeAmount { amount, expired });
+        self.balances.insert(&to, amounts)?;
+
+        let spendable = self.spendables.get(&to).await?.unwrap_or_default();
+        self.spendables
+            .insert(&to, spendable.saturating_add(amount))?;
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl StateInterface for CreditState {
+    fn set_reward_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), CreditError> {
+        for application_id in &application_ids {
+            self.reward_callers.insert(application_id)?;
+        }
+        Ok(())
+    }
+
+    fn set_transfer_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), CreditError> {
+        for application_id in &application_ids {
+            self.transfer_callers.insert(application_id)?;
+        }
+        Ok(())
+    }
+
+    fn admin(&self) -> Option<AccountOwner> {
+        *self.admin.get()
+    }
+
+    async fn export_page(
+        &mut self,
+        application_id: ApplicationId,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<StateRecord>, CreditError> {
+        match (offset, *self.export_target.get()) {
+            (0, None) => self.export_target.set(Some(application_id)),
+            (_, Some(target)) if target == application_id => {}
+            (_, Some(target)) => return Err(CreditError::ExportTargetMismatch(target)),
+            (_, None) => return Err(CreditError::NotExporting),
+        }
+        self.export_records(offset, limit).await
+    }
+
+    fn abort_export(&mut self) -> Result<(), CreditError> {
+        if self.export_target.get().is_none() {
+            return Err(CreditError::NotExporting);
+        }
+        self.export_target.set(None);
+        Ok(())
+    }
+}
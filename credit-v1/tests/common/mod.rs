@@ -0,0 +1,22 @@
+//! Fixtures shared by the tests running credit-v1 and credit-v2 in-memory states.
+
+#![allow(dead_code)]
+
+use linera_sdk::{
+    linera_base_types::{AccountOwner, ApplicationId, CryptoHash},
+    views::{KeyValueStore, View, ViewStorageContext},
+};
+
+pub fn owner(name: &str) -> AccountOwner {
+    AccountOwner::from(CryptoHash::test_hash(name))
+}
+
+pub fn application_id(name: &str) -> ApplicationId {
+    ApplicationId::new(CryptoHash::test_hash(name))
+}
+
+/// Either credit state, loaded from an empty in-memory store
+pub async fn new_state<S: View<ViewStorageContext>>() -> S {
+    let context = ViewStorageContext::new_unchecked(KeyValueStore::mock(), Vec::new(), ());
+    S::load(context).await.expect("Failed to load state")
+}
@@ -0,0 +1,322 @@
+//! Differential testing of credit-v2 against the credit-v1 reference state.
+//!
+//! Both states run the same steps in lockstep through `LedgerInterface` and must agree
+//! on the outcome of every step, on the supply and on every balance. proptest generates
+//! the steps and shrinks a diverging sequence to a minimal one.
+//!
+//! The versions read the `amount_alive_ms` setting in different units, see
+//! [`Version::LIFETIME_UNIT_MICROS`], so each one is instantiated with the same lifetime
+//! normalised to its own unit. Two behaviours differ on purpose:
+//! - credit-v2 rejects a reward beyond the supply balance, which credit-v1 saturates.
+//!   Such a reward must fail on credit-v2 and isn't applied to credit-v1.
+//! - credit-v1 restamps the lots a transfer moves, credit-v2 keeps their expiry. The
+//!   steps therefore run within one lifetime, where no lot expires in either version,
+//!   and a last liquidation past every expiry drains both.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{new_state, owner};
+use credit_v2::{
+    instantiation_argument::InstantiationArgument, interfaces::state::LedgerInterface,
+    state::errors::StateError,
+};
+use linera_sdk::{
+    linera_base_types::{AccountOwner, Amount, TimeDelta, Timestamp},
+    views::{View, ViewStorageContext},
+};
+use proptest::prelude::*;
+
+type V1 = credit_v1::state::CreditState;
+type V2 = credit_v2::state::CreditState;
+
+const OWNERS: usize = 4;
+/// Lifetime of the lots, the same instant for both versions
+const LIFETIME_MICROS: u64 = 1_000_000;
+const INITIAL_SUPPLY: u128 = 1000;
+// Generated rewards may add up beyond the supply, which exercises its rejection
+const MAX_STEPS: usize = 48;
+const MAX_AMOUNT: u128 = 100;
+
+/// A credit version under test
+trait Version: LedgerInterface + View<ViewStorageContext> {
+    /// Microseconds one unit of `amount_alive_ms` lasts: credit-v1 adds the setting to
+    /// the clock as microseconds, credit-v2 as milliseconds
+    const LIFETIME_UNIT_MICROS: u64;
+
+    fn supply_balance(&self) -> Amount;
+    fn supply(&self) -> Amount;
+    fn lifetime_micros(&self) -> u64;
+}
+
+impl Version for V1 {
+    const LIFETIME_UNIT_MICROS: u64 = 1;
+
+    fn supply_balance(&self) -> Amount {
+        *self._balance.get()
+    }
+
+    fn supply(&self) -> Amount {
+        *self._initial_supply.get()
+    }
+
+    fn lifetime_micros(&self) -> u64 {
+        *self.amount_alive_ms.get() * Self::LIFETIME_UNIT_MICROS
+    }
+}
+
+impl Version for V2 {
+    const LIFETIME_UNIT_MICROS: u64 = 1000;
+
+    fn supply_balance(&self) -> Amount {
+        *self._balance.get()
+    }
+
+    fn supply(&self) -> Amount {
+        *self._initial_supply.get()
+    }
+
+    fn lifetime_micros(&self) -> u64 {
+        *self.amount_alive_ms.get() * Self::LIFETIME_UNIT_MICROS
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Action {
+    Reward {
+        owner: usize,
+        amount: u128,
+    },
+    Transfer {
+        from: usize,
+        to: usize,
+        amount: u128,
+    },
+    Liquidate,
+}
+
+/// An action and how far the clock moves before it
+#[derive(Clone, Debug)]
+struct Step {
+    advance_micros: u64,
+    action: Action,
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        (0..OWNERS, 1..=MAX_AMOUNT).prop_map(|(owner, amount)| Action::Reward { owner, amount }),
+        (0..OWNERS, 0..OWNERS, 1..=MAX_AMOUNT).prop_map(|(from, to, amount)| Action::Transfer {
+            from,
+            to,
+            amount
+        }),
+        Just(Action::Liquidate),
+    ]
+}
+
+/// Steps whose advances add up to less than one lifetime
+fn steps() -> impl Strategy<Value = Vec<Step>> {
+    prop::collection::vec(
+        (0..LIFETIME_MICROS / MAX_STEPS as u64, action()).prop_map(|(advance_micros, action)| {
+            Step {
+                advance_micros,
+                action,
+            }
+        }),
+        1..MAX_STEPS,
+    )
+}
+
+/// Steps one microsecond apart
+fn at_once(actions: Vec<Action>) -> Vec<Step> {
+    actions
+        .into_iter()
+        .map(|action| Step {
+            advance_micros: 1,
+            action,
+        })
+        .collect()
+}
+
+fn owners() -> Vec<AccountOwner> {
+    (0..OWNERS).map(|i| owner(&format!("owner {i}"))).collect()
+}
+
+async fn instantiated<S: Version>() -> S {
+    let mut state = new_state::<S>().await;
+    state.instantiate(InstantiationArgument {
+        initial_supply: Amount::from_tokens(INITIAL_SUPPLY),
+        amount_alive_ms: LIFETIME_MICROS / S::LIFETIME_UNIT_MICROS,
+    });
+    state
+}
+
+async fn balances<S: Version>(state: &S, now: Timestamp) -> Vec<Amount> {
+    let mut balances = Vec::new();
+    for owner in owners() {
+        balances.push(
+            state
+                .alive_balance(owner, now)
+                .await
+                .expect("Failed to read balance"),
+        );
+    }
+    balances
+}
+
+async fn assert_same(steps: Vec<Step>) -> Result<(), TestCaseError> {
+    let owners = owners();
+    let mut v1 = instantiated::<V1>().await;
+    let mut v2 = instantiated::<V2>().await;
+    prop_assert_eq!(v1.lifetime_micros(), v2.lifetime_micros());
+
+    let mut now = Timestamp::from(0);
+    for (i, step) in steps.into_iter().enumerate() {
+        now = now.saturating_add(TimeDelta::from_micros(step.advance_micros));
+
+        let (v1_ok, v2_ok) = match step.action {
+            Action::Reward { owner, amount } => {
+                let amount = Amount::from_tokens(amount);
+                let v2_result = v2.reward(owners[owner], amount, now).await;
+                if v1.supply_balance() < amount {
+                    prop_assert!(
+                        matches!(v2_result, Err(StateError::InsufficientSupplyBalance { .. })),
+                        "step {}: reward beyond the supply balance: {:?}",
+                        i,
+                        v2_result
+                    );
+                    continue;
+                }
+                let v1_result = v1.reward(owners[owner], amount, now).await;
+                (v1_result.is_ok(), v2_result.is_ok())
+            }
+            Action::Transfer { from, to, amount } => {
+                let (from, to, amount) = (owners[from], owners[to], Amount::from_tokens(amount));
+                (
+                    v1.transfer(from, to, amount, now).await.is_ok(),
+                    v2.transfer(from, to, amount, now).await.is_ok(),
+                )
+            }
+            Action::Liquidate => (
+                v1.liquidate(now).await.is_ok(),
+                v2.liquidate(now).await.is_ok(),
+            ),
+        };
+        prop_assert_eq!(v1_ok, v2_ok, "step {}", i);
+        prop_assert_eq!(v1.supply_balance(), v2.supply_balance(), "step {}", i);
+        prop_assert_eq!(
+            balances(&v1, now).await,
+            balances(&v2, now).await,
+            "step {}",
+            i
+        );
+    }
+
+    // Past every expiry, restamped or not, all the credit is back in the supply
+    let end = now.saturating_add(TimeDelta::from_micros(2 * LIFETIME_MICROS + 1));
+    v1.liquidate(end).await.expect("Failed to liquidate");
+    v2.liquidate(end).await.expect("Failed to liquidate");
+    for supply in [v1.supply(), v2.supply()] {
+        prop_assert_eq!(supply, Amount::from_tokens(INITIAL_SUPPLY));
+    }
+    for supply_balance in [v1.supply_balance(), v2.supply_balance()] {
+        prop_assert_eq!(supply_balance, Amount::from_tokens(INITIAL_SUPPLY));
+    }
+    prop_assert_eq!(balances(&v1, end).await, balances(&v2, end).await);
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn versions_agree(steps in steps()) {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Failed to build runtime")
+            .block_on(assert_same(steps))?;
+    }
+}
+
+#[tokio::test]
+async fn rewards_and_transfers() {
+    assert_same(at_once(vec![
+        Action::Reward {
+            owner: 0,
+            amount: 10,
+        },
+        Action::Reward {
+            owner: 1,
+            amount: 5,
+        },
+        Action::Transfer {
+            from: 0,
+            to: 1,
+            amount: 11,
+        },
+        Action::Transfer {
+            from: 1,
+            to: 2,
+            amount: 16,
+        },
+        Action::Transfer {
+            from: 1,
+            to: 2,
+            amount: 1,
+        },
+        Action::Transfer {
+            from: 3,
+            to: 1,
+            amount: 1,
+        },
+        Action::Liquidate,
+    ]))
+    .await
+    .expect("Versions diverge");
+}
+
+#[tokio::test]
+async fn reward_beyond_the_supply_balance() {
+    assert_same(at_once(vec![
+        Action::Reward {
+            owner: 0,
+            amount: 600,
+        },
+        Action::Reward {
+            owner: 1,
+            amount: 401,
+        },
+        Action::Reward {
+            owner: 1,
+            amount: 400,
+        },
+    ]))
+    .await
+    .expect("Versions diverge");
+}
+
+#[tokio::test]
+async fn raw_lifetime_setting_diverges() {
+    let (owner, now) = (owners()[0], Timestamp::from(0));
+    let argument = InstantiationArgument {
+        initial_supply: Amount::from_tokens(INITIAL_SUPPLY),
+        amount_alive_ms: LIFETIME_MICROS,
+    };
+    let mut v1 = new_state::<V1>().await;
+    let mut v2 = new_state::<V2>().await;
+    v1.instantiate(argument.clone());
+    v2.instantiate(argument);
+    v1.reward(owner, Amount::from_tokens(10), now)
+        .await
+        .expect("Failed to reward");
+    v2.reward(owner, Amount::from_tokens(10), now)
+        .await
+        .expect("Failed to reward");
+
+    // The same setting lasts a thousand times longer in credit-v2
+    assert_eq!(v2.lifetime_micros(), 1000 * v1.lifetime_micros());
+    let later = Timestamp::from(LIFETIME_MICROS + 1);
+    assert_eq!(balances(&v1, later).await[0], Amount::ZERO);
+    assert_eq!(balances(&v2, later).await[0], Amount::from_tokens(10));
+}
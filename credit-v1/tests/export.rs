@@ -0,0 +1,138 @@
+//! Exporting a credit-v1 state page by page into a credit-v2 state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{application_id, new_state, owner};
+use credit_v1::{interfaces::state::StateInterface as _, CreditError};
+use credit_v2::{
+    instantiation_argument::InstantiationArgument,
+    interfaces::state::{LedgerInterface, StateInterface},
+    state::errors::StateError,
+};
+use linera_sdk::linera_base_types::{Amount, Timestamp};
+
+#[tokio::test]
+async fn export_round_trip() {
+    let owners = [owner("alice"), owner("bob"), owner("carol")];
+    let now = Timestamp::from(0);
+    let mut v1 = new_state::<credit_v1::state::CreditState>().await;
+    v1.instantiate(InstantiationArgument {
+        initial_supply: Amount::from_tokens(1000),
+        amount_alive_ms: 1_000_000,
+    });
+    for (i, owner) in owners.iter().enumerate() {
+        v1.reward(*owner, Amount::from_tokens(10 * (i as u128 + 1)), now)
+            .await
+            .expect("Failed to reward");
+    }
+    v1.transfer(owners[2], owners[0], Amount::from_tokens(5), now)
+        .await
+        .expect("Failed to transfer");
+
+    // Later pages are only served once the first page stopped the accounts
+    let target = application_id("credit-v2");
+    assert!(v1.export_page(target, 2, 2).await.is_err());
+    let first = v1
+        .export_page(target, 0, 2)
+        .await
+        .expect("Failed to export");
+    let second = v1
+        .export_page(target, 2, 2)
+        .await
+        .expect("Failed to export");
+    assert!(matches!(
+        v1.reward(owners[0], Amount::ONE, now).await,
+        Err(CreditError::Exporting)
+    ));
+
+    let mut v2 = new_state::<credit_v2::state::CreditState>().await;
+    v2.import_state(first.clone())
+        .await
+        .expect("Failed to import first page");
+    assert!(matches!(
+        LedgerInterface::reward(&mut v2, owners[0], Amount::ONE, now).await,
+        Err(StateError::ImportInProgress)
+    ));
+
+    // An aborted import starts over from the first page
+    v2.abort_import().expect("Failed to abort import");
+    assert!(matches!(v2.abort_import(), Err(StateError::NotImporting)));
+    v2.import_state(first)
+        .await
+        .expect("Failed to import first page");
+    v2.import_state(second)
+        .await
+        .expect("Failed to import second page");
+
+    assert!(v2
+        .check_invariants()
+        .await
+        .expect("Failed to check invariants")
+        .is_empty());
+    assert_eq!(*v1._balance.get(), *v2._balance.get());
+    for owner in owners {
+        assert_eq!(
+            v1.alive_balance(owner, now)
+                .await
+                .expect("Failed to read balance"),
+            LedgerInterface::alive_balance(&v2, owner, now)
+                .await
+                .expect("Failed to read balance"),
+        );
+    }
+}
+
+#[tokio::test]
+async fn aborted_export_lets_the_accounts_change() {
+    let alice = owner("alice");
+    let now = Timestamp::from(0);
+    let (target, other) = (application_id("credit-v2"), application_id("other"));
+    let mut v1 = new_state::<credit_v1::state::CreditState>().await;
+    v1.instantiate(InstantiationArgument {
+        initial_supply: Amount::from_tokens(1000),
+        amount_alive_ms: 1_000_000,
+    });
+    v1.reward(alice, Amount::from_tokens(10), now)
+        .await
+        .expect("Failed to reward");
+    assert!(matches!(v1.abort_export(), Err(CreditError::NotExporting)));
+
+    v1.export_page(target, 0, 1)
+        .await
+        .expect("Failed to export");
+    // The pages of a started export only go to its target
+    assert!(matches!(
+        v1.export_page(other, 0, 1).await,
+        Err(CreditError::ExportTargetMismatch(pinned)) if pinned == target
+    ));
+    assert!(matches!(
+        v1.export_page(other, 1, 1).await,
+        Err(CreditError::ExportTargetMismatch(_))
+    ));
+    assert!(matches!(
+        v1.transfer(alice, owner("bob"), Amount::ONE, now).await,
+        Err(CreditError::Exporting)
+    ));
+
+    v1.abort_export().expect("Failed to abort export");
+    assert!(matches!(
+        v1.export_page(target, 1, 1).await,
+        Err(CreditError::NotExporting)
+    ));
+    v1.reward(alice, Amount::ONE, now)
+        .await
+        .expect("Failed to reward");
+    v1.liquidate(now).await.expect("Failed to liquidate");
+    assert_eq!(
+        v1.alive_balance(alice, now)
+            .await
+            .expect("Failed to read balance"),
+        Amount::from_tokens(11)
+    );
+
+    // A new export, to another application too, starts over from the first page
+    let records = v1.export_page(other, 0, 1).await.expect("Failed to export");
+    assert!(!records.is_empty());
+}
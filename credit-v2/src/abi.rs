@@ -1,14 +1,34 @@
-use crate::instantiation_argument::InstantiationArgument;
+use crate::{
+    instantiation_argument::InstantiationArgument,
+    types::{BatchEntryResult, RewardEntry, RewardExpiry, StateRecord, TransferEntry},
+};
 use async_graphql::{Request, Response};
+use base::{handler::errors::HandlerError, types::Role};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
-    linera_base_types::{AccountOwner, Amount, ApplicationId, ContractAbi, ServiceAbi},
+    linera_base_types::{
+        AccountOwner, Amount, ApplicationId, ContractAbi, CryptoHash, ServiceAbi, Timestamp,
+    },
 };
 use serde::{Deserialize, Serialize};
 
 pub struct CreditAbi;
 
-pub type OperationResponse = ();
+/// Result of an operation applied on the creator chain, operations which are only forwarded
+/// to the creator chain return nothing
+#[derive(Debug, Deserialize, Serialize)]
+pub enum OperationResult {
+    /// One result per entry, only available when the batch runs on the creator chain
+    Batch(Vec<BatchEntryResult>),
+    /// The batch was sent to the creator chain, entries are applied or rejected there
+    /// as a whole and the outcome isn't reported back
+    Forwarded {
+        entries: u32,
+    },
+    ScheduleId(u64),
+}
+
+pub type OperationResponse = Result<Option<OperationResult>, HandlerError>;
 
 impl ContractAbi for CreditAbi {
     type Operation = Operation;
@@ -27,10 +47,17 @@ pub enum Operation {
         from: AccountOwner,
         to: AccountOwner,
         amount: Amount,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
+        /// Application which called the operation, a transfer caller may move the
+        /// credit of other accounts than the signer's
+        caller: Option<ApplicationId>,
     },
     TransferExt {
         to: AccountOwner,
         amount: Amount,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
     },
     SetRewardCallers {
         application_ids: Vec<ApplicationId>,
@@ -42,6 +69,82 @@ pub enum Operation {
     Reward {
         owner: AccountOwner,
         amount: Amount,
+        expiry: Option<RewardExpiry>,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
+    },
+    GrantRole {
+        owner: AccountOwner,
+        role: Role,
+    },
+    RevokeRole {
+        owner: AccountOwner,
+        role: Role,
+    },
+    RenounceRole {
+        role: Role,
+    },
+    Burn {
+        amount: Amount,
+    },
+    /// Answers `OperationResult::Batch` on the creator chain and
+    /// `OperationResult::Forwarded` elsewhere
+    BatchReward {
+        entries: Vec<RewardEntry>,
+    },
+    /// Same results as `BatchReward`
+    BatchTransfer {
+        entries: Vec<TransferEntry>,
+    },
+    Mint {
+        amount: Amount,
+    },
+    ScheduleReward {
+        owner: AccountOwner,
+        amount: Amount,
+        interval_ms: u64,
+        count: u32,
+        /// First installment time, now when not given
+        start_at: Option<Timestamp>,
+    },
+    CancelSchedule {
+        schedule_id: u64,
+    },
+    ProcessSchedules {
+        max: u32,
+    },
+    Hold {
+        owner: AccountOwner,
+        amount: Amount,
+        hold_id: String,
+        expires_at: Timestamp,
+    },
+    CaptureHold {
+        hold_id: String,
+        to: AccountOwner,
+    },
+    ReleaseHold {
+        hold_id: String,
+    },
+    UpdateConfig {
+        amount_alive_ms: u64,
+    },
+    ImportState {
+        records: Vec<StateRecord>,
+    },
+    ImportSnapshot {
+        records: Vec<StateRecord>,
+        hash: String,
+    },
+    /// Drops the records of an import which can't be finalized
+    AbortImport,
+    Freeze {
+        owner: AccountOwner,
+        /// Whether the account keeps receiving rewards while frozen
+        allow_rewards: bool,
+    },
+    Unfreeze {
+        owner: AccountOwner,
     },
 }
 
@@ -54,15 +157,22 @@ pub enum Message {
     Reward {
         owner: AccountOwner,
         amount: Amount,
+        expiry: Option<RewardExpiry>,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
     },
     Transfer {
         from: AccountOwner,
         to: AccountOwner,
         amount: Amount,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
     },
     TransferExt {
         to: AccountOwner,
         amount: Amount,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
     },
     SetRewardCallers {
         application_ids: Vec<ApplicationId>,
@@ -71,4 +181,24 @@ pub enum Message {
         application_ids: Vec<ApplicationId>,
     },
     RequestSubscribe,
+    GrantRole {
+        owner: AccountOwner,
+        role: Role,
+    },
+    RevokeRole {
+        owner: AccountOwner,
+        role: Role,
+    },
+    RenounceRole {
+        role: Role,
+    },
+    Burn {
+        amount: Amount,
+    },
+    BatchReward {
+        entries: Vec<RewardEntry>,
+    },
+    BatchTransfer {
+        entries: Vec<TransferEntry>,
+    },
 }
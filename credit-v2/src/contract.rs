@@ -2,10 +2,12 @@
 
 use std::{cell::RefCell, rc::Rc};
 
+use base::{interfaces::access_control::RoleStore, types::Role};
 use credit_v2::{
     abi::{CreditAbi, Message, Operation},
     instantiation_argument::InstantiationArgument,
     interfaces::state::StateInterface,
+    parameters::ApplicationParameters,
     state::CreditState,
 };
 use linera_sdk::{
@@ -28,7 +30,7 @@ impl WithContractAbi for CreditContract {
 impl Contract for CreditContract {
     type Message = Message;
     type InstantiationArgument = InstantiationArgument;
-    type Parameters = ();
+    type Parameters = ApplicationParameters;
     type EventValue = ();
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
@@ -42,8 +44,22 @@ impl Contract for CreditContract {
     }
 
     async fn instantiate(&mut self, argument: InstantiationArgument) {
-        self.runtime.borrow_mut().application_parameters();
+        let parameters = self.runtime.borrow_mut().application_parameters();
+        let argument = argument
+            .with_defaults(&parameters)
+            .expect("Invalid instantiation argument");
         self.state.borrow_mut().instantiate(argument);
+
+        // Creator is the first admin, who grants the other roles
+        let owner = self
+            .runtime
+            .borrow_mut()
+            .authenticated_signer()
+            .expect("Instantiation requires an authenticated signer");
+        self.state
+            .borrow_mut()
+            .set_roles(owner, vec![Role::Admin])
+            .expect("Failed to grant admin role");
     }
 
     async fn execute_operation(&mut self, operation: Operation) -> Self::Response {
@@ -51,7 +67,7 @@ impl Contract for CreditContract {
     }
 
     async fn execute_message(&mut self, message: Message) {
-        self.on_message(&message)
+        self.on_message(&message).await
     }
 
     async fn store(self) {
@@ -1,40 +1,89 @@
 use super::CreditContract;
 
+use base::{
+    handler::{errors::HandlerError, executor},
+    runtime::contract::ContractRuntimeAdapter,
+};
 use credit_v2::{
     abi::{Message, Operation, OperationResponse},
-    contract_inner::handlers::HandlerFactory,
-    runtime::contract::ContractRuntimeAdapter,
+    contract_inner::{
+        handlers::{types::ResponseOutcome, HandlerFactory},
+        leaderboard::update_leaderboard,
+    },
     state::adapter::StateAdapter,
 };
 
 impl CreditContract {
     pub async fn on_op(&mut self, op: &Operation) -> OperationResponse {
-        let runtime_context = ContractRuntimeAdapter::new(self.runtime.clone());
-        let state_adapter = StateAdapter::new(self.state.clone());
-
-        let outcome = match HandlerFactory::new(runtime_context, state_adapter, Some(op), None)
-            .unwrap()
-            .handle()
-            .await
-        {
-            Ok(outcome) => outcome,
-            Err(err) => panic!("Failed OP: {:?}: {err}", op),
-        };
+        match self.execute(Some(op), None).await {
+            Ok(outcome) => Ok(outcome.response),
+            Err(err) => {
+                log::warn!("Failed OP: {:?}: {err}", op);
+                Err(err)
+            }
+        }
+    }
 
-        // TODO: if messages are available, send it
-        // TODO: if events are available, emit it
-        ()
+    pub async fn on_message(&mut self, msg: &Message) {
+        // A rejected message comes back to its sender, where there's nothing to redo
+        if self.runtime.borrow_mut().message_is_bouncing() == Some(true) {
+            log::warn!("Bounced MSG {:?}", msg);
+            return;
+        }
+        // The state is rolled back, failing the message gets it rejected back to its sender
+        if let Err(err) = self.execute(None, Some(msg)).await {
+            panic!("Failed MSG {:?}: {err}", msg);
+        }
     }
 
-    pub fn on_message(&mut self, msg: &Message) {
-        let runtime_context = ContractRuntimeAdapter::new(self.runtime.clone());
-        let state_adapter = StateAdapter::new(self.state.clone());
+    /// Runs the handler as one unit of work, its leaderboard updates are only pushed once
+    /// its state changes are committed
+    async fn execute(
+        &mut self,
+        op: Option<&Operation>,
+        msg: Option<&Message>,
+    ) -> Result<ResponseOutcome, HandlerError> {
+        let outcome = executor::execute::<HandlerFactory, _, _, _>(
+            self.runtime.clone(),
+            self.state.clone(),
+            StateAdapter::new(self.state.clone()),
+            op,
+            msg,
+        )
+        .await?;
+
+        #[cfg(debug_assertions)]
+        self.check_invariants().await;
+
+        let mut runtime_context =
+            ContractRuntimeAdapter::new(self.runtime.clone(), self.state.clone());
+        update_leaderboard(
+            &mut runtime_context,
+            &StateAdapter::new(self.state.clone()),
+            &outcome.updated_accounts,
+        )
+        .await;
 
-        let outcome = match HandlerFactory::new(runtime_context, state_adapter, None, Some(msg)) {
-            Ok(outcome) => outcome,
-            Err(err) => panic!("Failed MSG {:?}: {err}", msg),
-        };
-        // TODO: if messages are available, send it
         // TODO: if events are available, emit it
+        Ok(outcome)
+    }
+
+    /// Checks the invariants after each committed unit of work. A paginated import only
+    /// conserves the supply once it is finalized, so the check waits until then.
+    #[cfg(debug_assertions)]
+    async fn check_invariants(&self) {
+        let state = self.state.borrow();
+        if state.header.get().importing {
+            return;
+        }
+        let violations = state
+            .check_invariants()
+            .await
+            .expect("Failed to check invariants");
+        assert!(
+            violations.is_empty(),
+            "Invariants violated: {:?}",
+            violations
+        );
     }
 }
@@ -1,2 +1,5 @@
+pub mod batch;
 pub mod errors;
 pub mod handlers;
+pub mod holds;
+pub mod leaderboard;
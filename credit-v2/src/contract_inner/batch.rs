@@ -0,0 +1,13 @@
+use crate::{state::errors::StateError, types::MAX_BATCH_SIZE};
+use base::handler::errors::HandlerError;
+
+/// Rejects empty and oversized batches before anything is applied or forwarded
+pub fn check_batch_size(len: usize) -> Result<(), HandlerError> {
+    if len == 0 || len > MAX_BATCH_SIZE {
+        return Err(HandlerError::state(StateError::InvalidBatchSize {
+            size: len,
+            max: MAX_BATCH_SIZE,
+        }));
+    }
+    Ok(())
+}
@@ -1,8 +1,23 @@
-use super::handlers::errors::HandlerError;
+use linera_sdk::linera_base_types::{AccountOwner, ApplicationId, ChainId};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+/// An error raised by the checks of the handlers themselves
+#[derive(Debug, Deserialize, Error, Serialize)]
 pub enum ContractError {
-    #[error(transparent)]
-    HandlerError(#[from] HandlerError),
+    #[error("{signer} can't hold the credit of {owner}")]
+    NotAccountOwner {
+        signer: AccountOwner,
+        owner: AccountOwner,
+    },
+
+    #[error("Message from {0} instead of the creator chain")]
+    NotFromCreatorChain(ChainId),
+
+    #[error("Transfer from {from} is neither signed by it nor called by a transfer caller")]
+    TransferNotAuthorized {
+        from: AccountOwner,
+        signer: Option<AccountOwner>,
+        caller: Option<ApplicationId>,
+    },
 }
@@ -1,50 +1,89 @@
-pub mod errors;
-pub mod interfaces;
+pub mod message;
 pub mod operation;
 pub mod types;
 
-use crate::abi::{Message, Operation};
+use crate::abi::{Message, Operation, OperationResult};
 use crate::interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface};
-use errors::HandlerError;
-use interfaces::Handler;
-use operation::transfer::TransferHandler;
+use base::{handler::roles, interfaces::access_control::AccessControl};
+use base_macros::env_handler;
+use message::{
+    batch_reward::BatchRewardMessageHandler, batch_transfer::BatchTransferMessageHandler,
+    burn::BurnMessageHandler, instantiation_argument::InstantiationArgumentMessageHandler,
+    liquidate::LiquidateMessageHandler, request_subscribe::RequestSubscribeMessageHandler,
+    reward::RewardMessageHandler, set_reward_callers::SetRewardCallersMessageHandler,
+    set_transfer_callers::SetTransferCallersMessageHandler, transfer::TransferMessageHandler,
+    transfer_ext::TransferExtMessageHandler,
+};
+use operation::{
+    abort_import::AbortImportHandler, batch_reward::BatchRewardHandler,
+    batch_transfer::BatchTransferHandler, burn::BurnHandler,
+    cancel_schedule::CancelScheduleHandler, capture_hold::CaptureHoldHandler,
+    freeze::FreezeHandler, grant_role::GrantRoleHandler, hold::HoldHandler,
+    import_snapshot::ImportSnapshotHandler, import_state::ImportStateHandler,
+    liquidate::LiquidateHandler, mint::MintHandler, process_schedules::ProcessSchedulesHandler,
+    release_hold::ReleaseHoldHandler, renounce_role::RenounceRoleHandler,
+    request_subscribe::RequestSubscribeHandler, revoke_role::RevokeRoleHandler,
+    reward::RewardHandler, schedule_reward::ScheduleRewardHandler,
+    set_reward_callers::SetRewardCallersHandler, set_transfer_callers::SetTransferCallersHandler,
+    transfer::TransferHandler, transfer_ext::TransferExtHandler, unfreeze::UnfreezeHandler,
+    update_config::UpdateConfigHandler,
+};
 
-pub struct HandlerFactory;
-
-impl HandlerFactory {
-    fn new_operation_handler(
-        runtime: impl ContractRuntimeContext + 'static,
-        state: impl StateInterface + 'static,
-        op: &Operation,
-    ) -> Box<dyn Handler> {
-        match op {
-            Operation::Transfer { from, to, amount } => {
-                Box::new(TransferHandler::new(runtime, state, from, to, amount))
-            }
-            _ => unimplemented!(),
-        }
-    }
+type GrantRoleMessageHandler<R, S> = roles::GrantRoleHandler<R, S, Message>;
+type RevokeRoleMessageHandler<R, S> = roles::RevokeRoleHandler<R, S, Message>;
+type RenounceRoleMessageHandler<R, S> = roles::RenounceRoleHandler<R, S, Message>;
 
-    fn new_message_handler(
-        runtime: impl ContractRuntimeContext,
-        state: impl StateInterface,
-        msg: &Message,
-    ) -> Box<dyn Handler> {
-        unimplemented!()
-    }
-
-    pub fn new(
-        runtime: impl ContractRuntimeContext + 'static,
-        state: impl StateInterface + 'static,
-        op: Option<&Operation>,
-        msg: Option<&Message>,
-    ) -> Result<Box<dyn Handler>, HandlerError> {
-        if let Some(op) = op {
-            return Ok(HandlerFactory::new_operation_handler(runtime, state, op));
-        }
-        if let Some(msg) = msg {
-            return Ok(HandlerFactory::new_message_handler(runtime, state, msg));
-        }
-        Err(HandlerError::InvalidOperationAndMessage)
-    }
-}
+#[env_handler(
+    runtime = ContractRuntimeContext + AccessControl,
+    state = StateInterface,
+    operation = Operation {
+        Liquidate => LiquidateHandler,
+        Transfer => TransferHandler,
+        TransferExt => TransferExtHandler,
+        SetRewardCallers => SetRewardCallersHandler,
+        SetTransferCallers => SetTransferCallersHandler,
+        RequestSubscribe => RequestSubscribeHandler,
+        Reward => RewardHandler,
+        GrantRole => GrantRoleHandler,
+        RevokeRole => RevokeRoleHandler,
+        RenounceRole => RenounceRoleHandler,
+        Burn => BurnHandler,
+        #[response]
+        BatchReward => BatchRewardHandler,
+        #[response]
+        BatchTransfer => BatchTransferHandler,
+        Mint => MintHandler,
+        #[response]
+        ScheduleReward => ScheduleRewardHandler,
+        CancelSchedule => CancelScheduleHandler,
+        #[response]
+        ProcessSchedules => ProcessSchedulesHandler,
+        Hold => HoldHandler,
+        CaptureHold => CaptureHoldHandler,
+        ReleaseHold => ReleaseHoldHandler,
+        UpdateConfig => UpdateConfigHandler,
+        ImportState => ImportStateHandler,
+        ImportSnapshot => ImportSnapshotHandler,
+        AbortImport => AbortImportHandler,
+        Freeze => FreezeHandler,
+        Unfreeze => UnfreezeHandler,
+    },
+    message = Message {
+        InstantiationArgument => InstantiationArgumentMessageHandler,
+        Liquidate => LiquidateMessageHandler,
+        Reward => RewardMessageHandler,
+        Transfer => TransferMessageHandler,
+        TransferExt => TransferExtMessageHandler,
+        SetRewardCallers => SetRewardCallersMessageHandler,
+        SetTransferCallers => SetTransferCallersMessageHandler,
+        RequestSubscribe => RequestSubscribeMessageHandler,
+        GrantRole { owner, role } => GrantRoleMessageHandler,
+        RevokeRole { owner, role } => RevokeRoleMessageHandler,
+        RenounceRole { role } => RenounceRoleMessageHandler,
+        Burn => BurnMessageHandler,
+        BatchReward => BatchRewardMessageHandler,
+        BatchTransfer => BatchTransferMessageHandler,
+    },
+    response = OperationResult,
+)]
+pub struct HandlerFactory;
@@ -1 +1,11 @@
+pub mod batch_reward;
+pub mod batch_transfer;
+pub mod burn;
+pub mod instantiation_argument;
+pub mod liquidate;
+pub mod request_subscribe;
+pub mod reward;
+pub mod set_reward_callers;
+pub mod set_transfer_callers;
 pub mod transfer;
+pub mod transfer_ext;
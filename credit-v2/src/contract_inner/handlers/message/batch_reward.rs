@@ -0,0 +1,44 @@
+use crate::{
+    abi::Message,
+    contract_inner::{batch::check_batch_size, handlers::types::HandlerOutcome},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    types::RewardEntry,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Message::BatchReward)]
+#[handler]
+pub struct BatchRewardMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    entries: Vec<RewardEntry>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for BatchRewardMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        check_batch_size(self.entries.len())?;
+
+        let now = self.runtime.system_time();
+        let results = self
+            .state
+            .batch_reward(self.entries.clone(), now)
+            .await
+            .map_err(HandlerError::state)?;
+
+        let owners = results
+            .iter()
+            .map(|result| result.owner)
+            .collect::<Vec<_>>();
+
+        Ok(HandlerOutcome {
+            updated_accounts: owners,
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    abi::Message,
+    contract_inner::{batch::check_batch_size, handlers::types::HandlerOutcome},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    types::TransferEntry,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Message::BatchTransfer)]
+#[handler]
+pub struct BatchTransferMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    entries: Vec<TransferEntry>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for BatchTransferMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        check_batch_size(self.entries.len())?;
+
+        let from = self
+            .runtime
+            .require_authenticated_signer()
+            .map_err(HandlerError::runtime)?;
+        let now = self.runtime.system_time();
+        let results = self
+            .state
+            .batch_transfer(from, self.entries.clone(), now)
+            .await
+            .map_err(HandlerError::state)?;
+
+        let mut owners = vec![from];
+        owners.extend(results.iter().map(|result| result.owner));
+
+        Ok(HandlerOutcome {
+            updated_accounts: owners,
+            ..Default::default()
+        })
+    }
+}
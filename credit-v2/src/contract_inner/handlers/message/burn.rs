@@ -0,0 +1,40 @@
+use crate::{
+    abi::Message,
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::Amount;
+
+#[operation_handler(Message::Burn)]
+#[handler]
+pub struct BurnMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    amount: Amount,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for BurnMessageHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let owner = self
+            .runtime
+            .require_authenticated_signer()
+            .map_err(HandlerError::runtime)?;
+
+        let now = self.runtime.system_time();
+        self.state
+            .burn(owner, self.amount, now)
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome {
+            updated_accounts: vec![owner],
+            ..Default::default()
+        })
+    }
+}
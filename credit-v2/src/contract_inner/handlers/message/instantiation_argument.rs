@@ -0,0 +1,35 @@
+use crate::{
+    abi::Message,
+    contract_inner::handlers::types::HandlerOutcome,
+    instantiation_argument::InstantiationArgument,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Message::InstantiationArgument)]
+#[handler]
+pub struct InstantiationArgumentMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    argument: InstantiationArgument,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for InstantiationArgumentMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let parameters = self.runtime.application_parameters();
+        let argument = self
+            .argument
+            .clone()
+            .with_defaults(&parameters)
+            .map_err(HandlerError::state)?;
+        self.state.instantiate(argument);
+
+        Ok(HandlerOutcome::default())
+    }
+}
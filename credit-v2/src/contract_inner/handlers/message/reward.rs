@@ -0,0 +1,53 @@
+use crate::{
+    abi::Message,
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    types::RewardExpiry,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount, CryptoHash};
+
+#[operation_handler(Message::Reward)]
+#[handler]
+pub struct RewardMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    owner: AccountOwner,
+    amount: Amount,
+    expiry: Option<RewardExpiry>,
+    memo: Option<String>,
+    reference: Option<CryptoHash>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for RewardMessageHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let now = self.runtime.system_time();
+        if let Some(expiry) = &self.expiry {
+            self.runtime
+                .application_parameters()
+                .check_expiry(expiry, now)
+                .map_err(HandlerError::state)?;
+        }
+        self.state
+            .reward(
+                self.owner,
+                self.amount,
+                now,
+                self.expiry,
+                self.memo.clone(),
+                self.reference,
+            )
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome {
+            updated_accounts: vec![self.owner],
+            ..Default::default()
+        })
+    }
+}
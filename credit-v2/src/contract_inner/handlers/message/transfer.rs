@@ -0,0 +1,70 @@
+use crate::{
+    abi::Message,
+    contract_inner::{errors::ContractError, handlers::types::HandlerOutcome},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, CryptoHash};
+
+#[operation_handler(Message::Transfer)]
+#[handler]
+pub struct TransferMessageHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    from: AccountOwner,
+    to: AccountOwner,
+    amount: Amount,
+    memo: Option<String>,
+    reference: Option<CryptoHash>,
+    caller: Option<ApplicationId>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message>
+    for TransferMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        // Only the owner or an allowed application may move the credit of `from`
+        let signer = self.runtime.authenticated_signer();
+        let authorized = match (signer, self.caller) {
+            (Some(signer), _) if signer == self.from => true,
+            (_, Some(application_id)) => self
+                .state
+                .is_transfer_caller(application_id)
+                .await
+                .map_err(HandlerError::state)?,
+            _ => false,
+        };
+        if !authorized {
+            return Err(HandlerError::permission(
+                ContractError::TransferNotAuthorized {
+                    from: self.from,
+                    signer,
+                    caller: self.caller,
+                },
+            ));
+        }
+
+        let now = self.runtime.system_time();
+        self.state
+            .transfer(
+                self.from,
+                self.to,
+                self.amount,
+                now,
+                self.memo.clone(),
+                self.reference,
+            )
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome {
+            updated_accounts: vec![self.from, self.to],
+            ..Default::default()
+        })
+    }
+}
@@ -1 +1,26 @@
+pub mod abort_import;
+pub mod batch_reward;
+pub mod batch_transfer;
+pub mod burn;
+pub mod cancel_schedule;
+pub mod capture_hold;
+pub mod freeze;
+pub mod grant_role;
+pub mod hold;
+pub mod import_snapshot;
+pub mod import_state;
+pub mod liquidate;
+pub mod mint;
+pub mod process_schedules;
+pub mod release_hold;
+pub mod renounce_role;
+pub mod request_subscribe;
+pub mod revoke_role;
+pub mod reward;
+pub mod schedule_reward;
+pub mod set_reward_callers;
+pub mod set_transfer_callers;
 pub mod transfer;
+pub mod transfer_ext;
+pub mod unfreeze;
+pub mod update_config;
@@ -0,0 +1,61 @@
+use crate::{
+    abi::{Message, Operation, OperationResult},
+    contract_inner::{batch::check_batch_size, handlers::types::ResponseOutcome},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    types::RewardEntry,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Operation::BatchReward)]
+#[handler]
+pub struct BatchRewardHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    entries: Vec<RewardEntry>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message, OperationResult>
+    for BatchRewardHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<ResponseOutcome, HandlerError> {
+        check_batch_size(self.entries.len())?;
+
+        let destination = self.runtime.application_creator_chain_id();
+        if self.runtime.chain_id() != destination {
+            let mut outcome =
+                ResponseOutcome::default().with_response(OperationResult::Forwarded {
+                    entries: self.entries.len() as u32,
+                });
+            outcome.send_message(
+                false,
+                destination,
+                Message::BatchReward {
+                    entries: self.entries.clone(),
+                },
+            );
+            return Ok(outcome);
+        }
+
+        // The state lives on this chain, so apply the batch now and report every entry
+        let now = self.runtime.system_time();
+        let results = self
+            .state
+            .batch_reward(self.entries.clone(), now)
+            .await
+            .map_err(HandlerError::state)?;
+
+        let owners = results
+            .iter()
+            .map(|result| result.owner)
+            .collect::<Vec<_>>();
+
+        let mut outcome = ResponseOutcome::default().with_response(OperationResult::Batch(results));
+        outcome.updated_accounts = owners;
+
+        Ok(outcome)
+    }
+}
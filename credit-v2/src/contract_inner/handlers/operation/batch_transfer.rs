@@ -0,0 +1,63 @@
+use crate::{
+    abi::{Message, Operation, OperationResult},
+    contract_inner::{batch::check_batch_size, handlers::types::ResponseOutcome},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    types::TransferEntry,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Operation::BatchTransfer)]
+#[handler]
+pub struct BatchTransferHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    entries: Vec<TransferEntry>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message, OperationResult>
+    for BatchTransferHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<ResponseOutcome, HandlerError> {
+        check_batch_size(self.entries.len())?;
+
+        let destination = self.runtime.application_creator_chain_id();
+        if self.runtime.chain_id() != destination {
+            let mut outcome =
+                ResponseOutcome::default().with_response(OperationResult::Forwarded {
+                    entries: self.entries.len() as u32,
+                });
+            outcome.send_message(
+                true,
+                destination,
+                Message::BatchTransfer {
+                    entries: self.entries.clone(),
+                },
+            );
+            return Ok(outcome);
+        }
+
+        // The state lives on this chain, so apply the batch now and report every entry
+        let from = self
+            .runtime
+            .require_authenticated_signer()
+            .map_err(HandlerError::runtime)?;
+        let now = self.runtime.system_time();
+        let results = self
+            .state
+            .batch_transfer(from, self.entries.clone(), now)
+            .await
+            .map_err(HandlerError::state)?;
+
+        let mut owners = vec![from];
+        owners.extend(results.iter().map(|result| result.owner));
+
+        let mut outcome = ResponseOutcome::default().with_response(OperationResult::Batch(results));
+        outcome.updated_accounts = owners;
+
+        Ok(outcome)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base::types::Role;
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Operation::CancelSchedule)]
+#[handler]
+pub struct CancelScheduleHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    schedule_id: u64,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for CancelScheduleHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+        self.runtime
+            .only_role(Role::Admin)
+            .await
+            .map_err(HandlerError::runtime)?;
+
+        self.state
+            .cancel_schedule(self.schedule_id)
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
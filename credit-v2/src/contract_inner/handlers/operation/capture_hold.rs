@@ -0,0 +1,43 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::{handlers::types::HandlerOutcome, holds::hold_operator},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::AccountOwner;
+
+#[operation_handler(Operation::CaptureHold)]
+#[handler]
+pub struct CaptureHoldHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    hold_id: String,
+    to: AccountOwner,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for CaptureHoldHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+
+        let operator = hold_operator(&mut self.runtime)?;
+        self.state
+            .capture_hold(self.hold_id.clone(), operator, self.to)
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome {
+            updated_accounts: vec![self.to],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base::types::Role;
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::AccountOwner;
+
+#[operation_handler(Operation::Freeze)]
+#[handler]
+pub struct FreezeHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    owner: AccountOwner,
+    allow_rewards: bool,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for FreezeHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+        let frozen_by = self
+            .runtime
+            .only_role(Role::Admin)
+            .await
+            .map_err(HandlerError::runtime)?;
+
+        let now = self.runtime.system_time();
+        self.state
+            .freeze(self.owner, self.allow_rewards, frozen_by, now)
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
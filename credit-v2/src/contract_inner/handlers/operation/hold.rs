@@ -0,0 +1,67 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::{
+        errors::ContractError, handlers::types::HandlerOutcome, holds::hold_operator,
+    },
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
+
+#[operation_handler(Operation::Hold)]
+#[handler]
+pub struct HoldHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    owner: AccountOwner,
+    amount: Amount,
+    hold_id: String,
+    expires_at: Timestamp,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for HoldHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+
+        // Only the holder can lock its own credit
+        let signer = self
+            .runtime
+            .require_authenticated_signer()
+            .map_err(HandlerError::runtime)?;
+        if signer != self.owner {
+            return Err(HandlerError::permission(ContractError::NotAccountOwner {
+                signer,
+                owner: self.owner,
+            }));
+        }
+
+        let operator = hold_operator(&mut self.runtime)?;
+        let now = self.runtime.system_time();
+        self.state
+            .hold(
+                self.hold_id.clone(),
+                self.owner,
+                operator,
+                self.amount,
+                self.expires_at,
+                now,
+            )
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome {
+            updated_accounts: vec![self.owner],
+            ..Default::default()
+        })
+    }
+}
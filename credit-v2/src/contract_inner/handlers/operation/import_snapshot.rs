@@ -0,0 +1,43 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    types::StateRecord,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base::types::Role;
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Operation::ImportSnapshot)]
+#[handler]
+pub struct ImportSnapshotHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    records: Vec<StateRecord>,
+    hash: String,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for ImportSnapshotHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+        self.runtime
+            .only_role(Role::Admin)
+            .await
+            .map_err(HandlerError::runtime)?;
+
+        self.state
+            .import_snapshot(self.records.clone(), self.hash.clone())
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    abi::{Message, Operation, OperationResult},
+    contract_inner::handlers::types::ResponseOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Operation::ProcessSchedules)]
+#[handler]
+pub struct ProcessSchedulesHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    max: u32,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message, OperationResult>
+    for ProcessSchedulesHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<ResponseOutcome, HandlerError> {
+        // Anyone may crank the schedules, only the due installments are issued
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+
+        let now = self.runtime.system_time();
+        let results = self
+            .state
+            .process_schedules(self.max, now)
+            .await
+            .map_err(HandlerError::state)?;
+
+        let owners = results
+            .iter()
+            .map(|result| result.owner)
+            .collect::<Vec<_>>();
+
+        let mut outcome = ResponseOutcome::default().with_response(OperationResult::Batch(results));
+        outcome.updated_accounts = owners;
+
+        Ok(outcome)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::{handlers::types::HandlerOutcome, holds::hold_operator},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Operation::ReleaseHold)]
+#[handler]
+pub struct ReleaseHoldHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    hold_id: String,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for ReleaseHoldHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+
+        let operator = hold_operator(&mut self.runtime)?;
+        let hold = self
+            .state
+            .release_hold(self.hold_id.clone(), operator)
+            .await
+            .map_err(HandlerError::state)?;
+
+        Ok(HandlerOutcome {
+            updated_accounts: vec![hold.owner],
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::types::Role;
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::AccountOwner;
+
+#[operation_handler(Operation::RevokeRole)]
+#[handler]
+pub struct RevokeRoleHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    _state: S,
+
+    owner: AccountOwner,
+    role: Role,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for RevokeRoleHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        let destination = self.runtime.application_creator_chain_id();
+        let mut outcome = HandlerOutcome::default();
+        outcome.send_message(
+            true,
+            destination,
+            Message::RevokeRole {
+                owner: self.owner,
+                role: self.role,
+            },
+        );
+
+        Ok(outcome)
+    }
+}
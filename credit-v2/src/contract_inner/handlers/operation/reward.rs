@@ -0,0 +1,56 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    state::transactions::check_memo,
+    types::RewardExpiry,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount, CryptoHash};
+
+#[operation_handler(Operation::Reward)]
+#[handler]
+pub struct RewardHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    _state: S,
+
+    owner: AccountOwner,
+    amount: Amount,
+    expiry: Option<RewardExpiry>,
+    memo: Option<String>,
+    reference: Option<CryptoHash>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for RewardHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        // Checked before forwarding, a rejected message can't be reported to the signer
+        check_memo(&self.memo).map_err(HandlerError::state)?;
+        if let Some(expiry) = &self.expiry {
+            let now = self.runtime.system_time();
+            self.runtime
+                .application_parameters()
+                .check_expiry(expiry, now)
+                .map_err(HandlerError::state)?;
+        }
+
+        let destination = self.runtime.application_creator_chain_id();
+        let mut outcome = HandlerOutcome::default();
+        outcome.send_message(
+            false,
+            destination,
+            Message::Reward {
+                owner: self.owner,
+                amount: self.amount,
+                expiry: self.expiry,
+                memo: self.memo.clone(),
+                reference: self.reference,
+            },
+        );
+
+        Ok(outcome)
+    }
+}
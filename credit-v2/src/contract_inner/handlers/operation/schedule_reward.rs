@@ -0,0 +1,54 @@
+use crate::{
+    abi::{Message, Operation, OperationResult},
+    contract_inner::handlers::types::ResponseOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base::types::Role;
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
+
+#[operation_handler(Operation::ScheduleReward)]
+#[handler]
+pub struct ScheduleRewardHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    owner: AccountOwner,
+    amount: Amount,
+    interval_ms: u64,
+    count: u32,
+    start_at: Option<Timestamp>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message, OperationResult>
+    for ScheduleRewardHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<ResponseOutcome, HandlerError> {
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+        self.runtime
+            .only_role(Role::Admin)
+            .await
+            .map_err(HandlerError::runtime)?;
+
+        let start_at = self.start_at.unwrap_or_else(|| self.runtime.system_time());
+        let schedule_id = self
+            .state
+            .create_schedule(
+                self.owner,
+                self.amount,
+                self.interval_ms,
+                self.count,
+                start_at,
+            )
+            .map_err(HandlerError::state)?;
+
+        Ok(ResponseOutcome::default().with_response(OperationResult::ScheduleId(schedule_id)))
+    }
+}
@@ -1,41 +1,49 @@
 use crate::{
-    contract_inner::handlers::{errors::HandlerError, interfaces::Handler, types::HandlerOutcome},
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
     interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    state::transactions::check_memo,
 };
 use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
 
-use linera_sdk::linera_base_types::{AccountOwner, Amount};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, CryptoHash};
 
+#[operation_handler(Operation::Transfer)]
+#[handler]
 pub struct TransferHandler<R: ContractRuntimeContext, S: StateInterface> {
     runtime: R,
-    state: S,
+    _state: S,
 
     from: AccountOwner,
     to: AccountOwner,
     amount: Amount,
-}
-
-impl<R: ContractRuntimeContext, S: StateInterface> TransferHandler<R, S> {
-    pub fn new(
-        runtime: R,
-        state: S,
-        from: &AccountOwner,
-        to: &AccountOwner,
-        amount: &Amount,
-    ) -> Self {
-        Self {
-            state,
-            runtime,
-            from: *from,
-            to: *to,
-            amount: *amount,
-        }
-    }
+    memo: Option<String>,
+    reference: Option<CryptoHash>,
 }
 
 #[async_trait(?Send)]
-impl<R: ContractRuntimeContext, S: StateInterface> Handler for TransferHandler<R, S> {
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for TransferHandler<R, S> {
     async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
-        unimplemented!()
+        // Checked before forwarding, a rejected message can't be reported to the signer
+        check_memo(&self.memo).map_err(HandlerError::state)?;
+
+        let destination = self.runtime.application_creator_chain_id();
+        let mut outcome = HandlerOutcome::default();
+        outcome.send_message(
+            true,
+            destination,
+            Message::Transfer {
+                from: self.from,
+                to: self.to,
+                amount: self.amount,
+                memo: self.memo.clone(),
+                reference: self.reference,
+                caller: self.runtime.authenticated_caller_id(),
+            },
+        );
+
+        Ok(outcome)
     }
 }
@@ -0,0 +1,46 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    state::transactions::check_memo,
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount, CryptoHash};
+
+#[operation_handler(Operation::TransferExt)]
+#[handler]
+pub struct TransferExtHandler<R: ContractRuntimeContext, S: StateInterface> {
+    runtime: R,
+    _state: S,
+
+    to: AccountOwner,
+    amount: Amount,
+    memo: Option<String>,
+    reference: Option<CryptoHash>,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext, S: StateInterface> Handler<Message> for TransferExtHandler<R, S> {
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        // Checked before forwarding, a rejected message can't be reported to the signer
+        check_memo(&self.memo).map_err(HandlerError::state)?;
+
+        let destination = self.runtime.application_creator_chain_id();
+        let mut outcome = HandlerOutcome::default();
+        outcome.send_message(
+            true,
+            destination,
+            Message::TransferExt {
+                to: self.to,
+                amount: self.amount,
+                memo: self.memo.clone(),
+                reference: self.reference,
+            },
+        );
+
+        Ok(outcome)
+    }
+}
@@ -1,6 +1,6 @@
-use crate::abi::Message;
+use crate::abi::{Message, OperationResult};
 
-#[derive(Debug, Default)]
-pub struct HandlerOutcome {
-    pub messages: Vec<Message>,
-}
+pub type HandlerOutcome = base::handler::types::HandlerOutcome<Message>;
+
+/// Outcome of the operations answering with an `OperationResult`
+pub type ResponseOutcome = base::handler::types::HandlerOutcome<Message, OperationResult>;
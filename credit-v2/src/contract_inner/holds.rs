@@ -0,0 +1,15 @@
+use crate::interfaces::runtime::contract::ContractRuntimeContext;
+use base::handler::errors::HandlerError;
+use linera_sdk::linera_base_types::AccountOwner;
+
+/// Operator of a hold: the calling application if any, else the signer
+pub fn hold_operator(
+    runtime: &mut impl ContractRuntimeContext,
+) -> Result<AccountOwner, HandlerError> {
+    if let Some(application_id) = runtime.authenticated_caller_id() {
+        return Ok(AccountOwner::from(application_id));
+    }
+    runtime
+        .require_authenticated_signer()
+        .map_err(HandlerError::runtime)
+}
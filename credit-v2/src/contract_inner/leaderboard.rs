@@ -0,0 +1,57 @@
+use crate::interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface};
+use base::handler::errors::HandlerError;
+use leaderboard::{
+    abi::{LeaderBoardAbi, Operation as LeaderBoardOperation},
+    state::errors::StateError as LeaderBoardStateError,
+};
+use linera_sdk::linera_base_types::AccountOwner;
+
+/// Pushes the latest balance of `owners` to the configured leaderboard, if any.
+///
+/// Called by the contract once the handler's changes are committed, so a rolled back
+/// handler never reaches the leaderboard.
+pub async fn update_leaderboard(
+    runtime: &mut impl ContractRuntimeContext,
+    state: &impl StateInterface,
+    owners: &[AccountOwner],
+) {
+    let Some(application_id) = runtime.application_parameters().leaderboard_application_id else {
+        return;
+    };
+    let application_id = application_id.with_abi::<LeaderBoardAbi>();
+
+    let now = runtime.system_time();
+    for owner in owners {
+        let value = match state.alive_balance(*owner, now).await {
+            Ok(value) => value,
+            Err(err) => {
+                log::warn!("Failed to read balance of {owner}: {err}");
+                continue;
+            }
+        };
+        // A rejected leaderboard update must not revert the credit change itself
+        match runtime.call_application(
+            true,
+            application_id,
+            &LeaderBoardOperation::UpdateValue {
+                owner: *owner,
+                value,
+            },
+        ) {
+            Ok(()) => {}
+            Err(err) if is_banned(&err) => log::debug!("Owner {owner} is banned from leaderboard"),
+            Err(err) => log::warn!("Failed to update leaderboard of {owner}: {err}"),
+        }
+    }
+}
+
+/// Banned owners are kept off the board on purpose, it's not a failed sync
+fn is_banned(err: &HandlerError) -> bool {
+    let Some(layer) = err.layer() else {
+        return false;
+    };
+    matches!(
+        layer.decode::<LeaderBoardStateError>(),
+        Some(LeaderBoardStateError::BannedOwner(_))
+    )
+}
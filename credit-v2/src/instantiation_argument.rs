@@ -1,8 +1,26 @@
+use crate::{parameters::ApplicationParameters, state::errors::StateError};
 use linera_sdk::linera_base_types::Amount;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct InstantiationArgument {
     pub initial_supply: Amount,
+    /// Lifetime of the issued lots, zero takes `default_ttl_ms` of the parameters
     pub amount_alive_ms: u64,
 }
+
+impl InstantiationArgument {
+    /// Fills the lifetime from the application parameters and checks the argument
+    /// against them. There's no default supply, it must be given.
+    pub fn with_defaults(mut self, parameters: &ApplicationParameters) -> Result<Self, StateError> {
+        parameters.validate()?;
+        if self.initial_supply == Amount::ZERO {
+            return Err(StateError::InvalidInitialSupply);
+        }
+        if self.amount_alive_ms == 0 {
+            self.amount_alive_ms = parameters.default_ttl_ms;
+        }
+        parameters.check_ttl(self.amount_alive_ms)?;
+        Ok(self)
+    }
+}
@@ -1,2 +1 @@
-pub mod base;
 pub mod contract;
@@ -1,25 +1,13 @@
-use super::base::BaseRuntimeContext;
-use crate::abi::Message;
-use linera_sdk::{
-    abi::ContractAbi,
-    linera_base_types::{AccountOwner, ApplicationId, ChainId},
-};
+use crate::{abi::Message, parameters::ApplicationParameters};
+use base::interfaces::runtime::contract::ContractRuntimeContext as BaseContractRuntimeContext;
 
-pub trait ContractRuntimeContext: BaseRuntimeContext {
-    type Error;
-
-    fn authenticated_signer(&mut self) -> Option<AccountOwner>;
-    fn require_authenticated_signer(&mut self) -> Result<AccountOwner, Self::Error>;
-
-    fn send_message(&mut self, destionation: ChainId, message: Message);
-
-    fn message_origin_chain_id(&mut self) -> Option<ChainId>;
-    fn require_message_origin_chain_id(&mut self) -> Result<ChainId, Self::Error>;
+/// Contract runtime bound to the credit application's message and parameters types
+pub trait ContractRuntimeContext:
+    BaseContractRuntimeContext<Message = Message, Parameters = ApplicationParameters>
+{
+}
 
-    fn call_application<A: ContractAbi + Send>(
-        &mut self,
-        authenticated: bool,
-        application: ApplicationId<A>,
-        call: &A::Operation,
-    ) -> A::Response;
+impl<T: BaseContractRuntimeContext<Message = Message, Parameters = ApplicationParameters>>
+    ContractRuntimeContext for T
+{
 }
@@ -1,24 +1,191 @@
-use crate::instantiation_argument::InstantiationArgument;
+use crate::{
+    instantiation_argument::InstantiationArgument,
+    types::{
+        AgeAmount, BatchEntryResult, FreezeRecord, Hold, InvariantViolation, RewardEntry,
+        RewardExpiry, SnapshotPage, StateRecord, SupplyChange, Transaction, TransferEntry,
+    },
+};
 use async_trait::async_trait;
-use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, Timestamp};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, CryptoHash, Timestamp};
 
 #[async_trait(?Send)]
 pub trait StateInterface {
-    type Error;
+    type Error: std::fmt::Debug + std::error::Error + 'static;
 
     fn instantiate(&mut self, argument: InstantiationArgument);
     fn instantiation_argument(&self) -> InstantiationArgument;
     fn initial_supply(&self) -> Amount;
-    async fn balance(&self, owner: AccountOwner) -> Amount;
+    /// Credit of `owner` whose lots are not yet expired at `now`
+    async fn alive_balance(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Amount, Self::Error>;
+    async fn reward(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+        expiry: Option<RewardExpiry>,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
+    ) -> Result<(), Self::Error>;
+    async fn liquidate(&mut self, now: Timestamp) -> Result<(), Self::Error>;
+    fn set_reward_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), Self::Error>;
+    fn set_transfer_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), Self::Error>;
+    async fn is_transfer_caller(&self, application_id: ApplicationId) -> Result<bool, Self::Error>;
+    async fn transfer(
+        &mut self,
+        from: AccountOwner,
+        to: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
+    ) -> Result<(), Self::Error>;
+    async fn batch_reward(
+        &mut self,
+        entries: Vec<RewardEntry>,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, Self::Error>;
+    async fn batch_transfer(
+        &mut self,
+        from: AccountOwner,
+        entries: Vec<TransferEntry>,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, Self::Error>;
+    async fn hold(
+        &mut self,
+        hold_id: String,
+        owner: AccountOwner,
+        operator: AccountOwner,
+        amount: Amount,
+        expires_at: Timestamp,
+        now: Timestamp,
+    ) -> Result<(), Self::Error>;
+    /// Credits the held lots to `to` and returns the captured hold, unless either side is frozen
+    async fn capture_hold(
+        &mut self,
+        hold_id: String,
+        operator: AccountOwner,
+        to: AccountOwner,
+    ) -> Result<Hold, Self::Error>;
+    /// Credits the held lots back to the holder and returns the released hold
+    async fn release_hold(
+        &mut self,
+        hold_id: String,
+        operator: AccountOwner,
+    ) -> Result<Hold, Self::Error>;
+    fn create_schedule(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        interval_ms: u64,
+        count: u32,
+        start_at: Timestamp,
+    ) -> Result<u64, Self::Error>;
+    async fn cancel_schedule(&mut self, schedule_id: u64) -> Result<(), Self::Error>;
+    async fn process_schedules(
+        &mut self,
+        max: u32,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, Self::Error>;
+    async fn burn(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), Self::Error>;
+    fn mint(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), Self::Error>;
+    fn update_config(
+        &mut self,
+        amount_alive_ms: u64,
+        max_ttl_ms: u64,
+        changed_by: AccountOwner,
+        now: Timestamp,
+    ) -> Result<(), Self::Error>;
+    /// Freezes `owner`, replacing its previous freeze if any
+    fn freeze(
+        &mut self,
+        owner: AccountOwner,
+        allow_rewards: bool,
+        frozen_by: AccountOwner,
+        now: Timestamp,
+    ) -> Result<(), Self::Error>;
+    async fn unfreeze(&mut self, owner: AccountOwner) -> Result<(), Self::Error>;
+    async fn import_state(&mut self, records: Vec<StateRecord>) -> Result<(), Self::Error>;
+    async fn import_snapshot(
+        &mut self,
+        records: Vec<StateRecord>,
+        hash: String,
+    ) -> Result<(), Self::Error>;
+    /// Drops the records of an open import
+    fn abort_import(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Read-only view of the state used by service queries
+#[async_trait]
+pub trait StateQueryInterface: Send + Sync {
+    type Error: std::fmt::Debug + std::error::Error + Send + Sync + 'static;
+
+    fn initial_supply(&self) -> Amount;
+    fn supply_balance(&self) -> Amount;
+    async fn balance(&self, owner: AccountOwner, now: Timestamp) -> Result<Amount, Self::Error>;
+    async fn alive_amounts(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Vec<AgeAmount>, Self::Error>;
+    async fn check_invariants(&self) -> Result<Vec<InvariantViolation>, Self::Error>;
+    async fn held_balance(&self, owner: AccountOwner) -> Result<Amount, Self::Error>;
+    async fn hold(&self, hold_id: String) -> Result<Option<Hold>, Self::Error>;
+    async fn transactions(&self, offset: u32, limit: u32) -> Result<Vec<Transaction>, Self::Error>;
+    async fn transactions_by_reference(
+        &self,
+        reference: CryptoHash,
+    ) -> Result<Vec<Transaction>, Self::Error>;
+    async fn supply_history(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SupplyChange>, Self::Error>;
+    async fn snapshot(&self, offset: u32, limit: u32) -> Result<SnapshotPage, Self::Error>;
+    async fn frozen_accounts(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<FreezeRecord>, Self::Error>;
+}
+
+/// Rewards, transfers and liquidation, the part of the credit which credit-v1 also has.
+/// The differential tests run both versions through it.
+#[async_trait(?Send)]
+pub trait LedgerInterface {
+    type Error: std::fmt::Debug + std::error::Error + 'static;
+
+    fn instantiate(&mut self, argument: InstantiationArgument);
+    async fn alive_balance(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Amount, Self::Error>;
     async fn reward(
         &mut self,
         owner: AccountOwner,
         amount: Amount,
         now: Timestamp,
     ) -> Result<(), Self::Error>;
-    async fn liquidate(&mut self, now: Timestamp);
-    fn set_reward_callers(&mut self, application_ids: Vec<ApplicationId>);
-    fn set_transfer_callers(&mut self, application_ids: Vec<ApplicationId>);
     async fn transfer(
         &mut self,
         from: AccountOwner,
@@ -26,4 +193,5 @@ pub trait StateInterface {
         amount: Amount,
         now: Timestamp,
     ) -> Result<(), Self::Error>;
+    async fn liquidate(&mut self, now: Timestamp) -> Result<(), Self::Error>;
 }
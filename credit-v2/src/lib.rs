@@ -2,6 +2,6 @@ pub mod abi;
 pub mod contract_inner;
 pub mod instantiation_argument;
 pub mod interfaces;
-pub mod runtime;
+pub mod parameters;
 pub mod state;
 pub mod types;
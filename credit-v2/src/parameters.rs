@@ -0,0 +1,68 @@
+use async_graphql::SimpleObject;
+use linera_sdk::linera_base_types::{Amount, ApplicationId, Timestamp};
+use serde::{Deserialize, Serialize};
+
+use crate::{state::errors::StateError, types::RewardExpiry};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, SimpleObject)]
+pub struct ApplicationParameters {
+    /// Leaderboard which tracks owner balances. It must be created on the same chain as the
+    /// credit application, and should use the `Replace` aggregation mode.
+    pub leaderboard_application_id: Option<ApplicationId>,
+    /// Display name of the credit, shown by clients through the `parameters` query
+    pub token_name: String,
+    pub token_symbol: String,
+    /// Decimal places clients show, at most the 18 of `Amount`
+    pub decimals: u8,
+    /// Lifetime of the issued lots when the instantiation argument doesn't set one
+    pub default_ttl_ms: u64,
+    /// Upper bound of the lifetime set by `UpdateConfig`, zero means unbounded
+    pub max_ttl_ms: u64,
+}
+
+impl ApplicationParameters {
+    /// Rejects parameters the application can't be created with
+    pub fn validate(&self) -> Result<(), StateError> {
+        if u32::from(self.decimals) > Amount::DECIMAL_PLACES.into() {
+            return Err(StateError::InvalidDecimals {
+                decimals: self.decimals,
+                max: Amount::DECIMAL_PLACES.into(),
+            });
+        }
+        if self.default_ttl_ms > 0 {
+            self.check_ttl(self.default_ttl_ms)?;
+        }
+        Ok(())
+    }
+
+    /// Rejects a lot lifetime of zero or above `max_ttl_ms`
+    pub fn check_ttl(&self, ttl_ms: u64) -> Result<(), StateError> {
+        if ttl_ms == 0 || (self.max_ttl_ms > 0 && ttl_ms > self.max_ttl_ms) {
+            return Err(StateError::InvalidTtl {
+                ttl_ms,
+                max_ttl_ms: self.max_ttl_ms,
+            });
+        }
+        Ok(())
+    }
+
+    /// Rejects a reward expiry further than `max_ttl_ms` from `now`, and `never: false`
+    pub fn check_expiry(&self, expiry: &RewardExpiry, now: Timestamp) -> Result<(), StateError> {
+        match *expiry {
+            RewardExpiry::TtlMs(ttl_ms) => self.check_ttl(ttl_ms),
+            RewardExpiry::At(expired) => {
+                // Expiries at or before `now` are rejected by the state with `InvalidExpiry`
+                let ttl_ms = expired.delta_since(now).as_micros() / 1000;
+                if self.max_ttl_ms > 0 && ttl_ms > self.max_ttl_ms {
+                    return Err(StateError::InvalidTtl {
+                        ttl_ms,
+                        max_ttl_ms: self.max_ttl_ms,
+                    });
+                }
+                Ok(())
+            }
+            RewardExpiry::Never(true) => Ok(()),
+            RewardExpiry::Never(false) => Err(StateError::InvalidNeverExpiry),
+        }
+    }
+}
@@ -1,12 +1,15 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
 use async_graphql::{EmptySubscription, Request, Response, Schema};
+use base::runtime::service::ServiceRuntimeAdapter;
 use credit_v2::abi::Operation;
+use credit_v2::parameters::ApplicationParameters;
 use credit_v2::state::CreditState;
 use linera_sdk::{
     graphql::GraphQLMutationRoot, linera_base_types::WithServiceAbi, views::View, Service,
     ServiceRuntime,
 };
+use service_impl::QueryRoot;
 use std::sync::Arc;
 
 pub struct CreditService {
@@ -21,7 +24,7 @@ impl WithServiceAbi for CreditService {
 }
 
 impl Service for CreditService {
-    type Parameters = ();
+    type Parameters = ApplicationParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = CreditState::load(runtime.root_view_storage_context())
@@ -34,8 +37,9 @@ impl Service for CreditService {
     }
 
     async fn handle_query(&self, request: Request) -> Response {
+        let runtime_context = Arc::new(ServiceRuntimeAdapter::new(self.runtime.clone()));
         let schema = Schema::build(
-            self.state.clone(),
+            QueryRoot::new(self.state.clone(), runtime_context),
             Operation::mutation_root(self.runtime.clone()),
             EmptySubscription,
         )
@@ -43,3 +47,5 @@ impl Service for CreditService {
         schema.execute(request).await
     }
 }
+
+mod service_impl;
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use super::CreditService;
+
+use async_graphql::{MergedObject, Object};
+use base::{
+    interfaces::runtime::service::ServiceRuntimeContext, runtime::service::ServiceRuntimeAdapter,
+};
+use credit_v2::{
+    interfaces::state::StateQueryInterface,
+    parameters::ApplicationParameters,
+    state::CreditState,
+    types::{
+        AgeAmount, FreezeRecord, Hold, InvariantViolation, SnapshotPage, SupplyChange, Transaction,
+    },
+};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, CryptoHash};
+
+pub struct CreditQuery {
+    state: Arc<CreditState>,
+    runtime: Arc<ServiceRuntimeAdapter<CreditService>>,
+}
+
+#[Object]
+impl CreditQuery {
+    /// Balance of `owner` without the amounts already expired but not liquidated yet
+    async fn alive_balance(&self, owner: AccountOwner) -> async_graphql::Result<Amount> {
+        let now = self.runtime.system_time();
+        Ok(self.state.balance(owner, now).await?)
+    }
+
+    /// Amounts of `owner` which are not expired yet
+    async fn alive_amounts(&self, owner: AccountOwner) -> async_graphql::Result<Vec<AgeAmount>> {
+        let now = self.runtime.system_time();
+        Ok(self.state.alive_amounts(owner, now).await?)
+    }
+
+    /// Immutable parameters the application was created with
+    async fn parameters(&self) -> ApplicationParameters {
+        self.runtime.application_parameters()
+    }
+
+    /// Credit of `owner` locked by holds
+    async fn held_balance(&self, owner: AccountOwner) -> async_graphql::Result<Amount> {
+        Ok(self.state.held_balance(owner).await?)
+    }
+
+    async fn hold(&self, hold_id: String) -> async_graphql::Result<Option<Hold>> {
+        Ok(StateQueryInterface::hold(self.state.as_ref(), hold_id).await?)
+    }
+
+    /// Supply minted so far, less the burnt credit
+    async fn initial_supply(&self) -> Amount {
+        self.state.initial_supply()
+    }
+
+    /// Supply which is not rewarded yet
+    async fn supply_balance(&self) -> Amount {
+        self.state.supply_balance()
+    }
+
+    /// Diagnostic of the supply invariants, empty when the state is consistent
+    async fn invariant_violations(&self) -> async_graphql::Result<Vec<InvariantViolation>> {
+        Ok(self.state.check_invariants().await?)
+    }
+
+    /// Rewards and transfers in the order they happened
+    async fn transactions(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> async_graphql::Result<Vec<Transaction>> {
+        Ok(self.state.transactions(offset, limit).await?)
+    }
+
+    /// Rewards and transfers carrying `reference`
+    async fn transactions_by_reference(
+        &self,
+        reference: CryptoHash,
+    ) -> async_graphql::Result<Vec<Transaction>> {
+        Ok(self.state.transactions_by_reference(reference).await?)
+    }
+
+    /// Mints and burns in the order they happened
+    async fn supply_history(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> async_graphql::Result<Vec<SupplyChange>> {
+        Ok(self.state.supply_history(offset, limit).await?)
+    }
+
+    /// Page of the state snapshot starting at the `offset`th owner, the first page
+    /// carries the supply and callers and the last one the totals to verify
+    async fn snapshot(&self, offset: u32, limit: u32) -> async_graphql::Result<SnapshotPage> {
+        Ok(self.state.snapshot(offset, limit).await?)
+    }
+
+    /// Frozen accounts in owner order with who froze them and whether they keep
+    /// receiving rewards
+    async fn frozen_accounts(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> async_graphql::Result<Vec<FreezeRecord>> {
+        Ok(self.state.frozen_accounts(offset, limit).await?)
+    }
+}
+
+#[derive(MergedObject)]
+pub struct QueryRoot(Arc<CreditState>, CreditQuery);
+
+impl QueryRoot {
+    pub fn new(
+        state: Arc<CreditState>,
+        runtime: Arc<ServiceRuntimeAdapter<CreditService>>,
+    ) -> Self {
+        Self(state.clone(), CreditQuery { state, runtime })
+    }
+}
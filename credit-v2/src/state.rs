@@ -1,22 +1,66 @@
-use crate::types::AgeAmounts;
+use crate::types::{
+    AgeAmounts, ConfigChange, Freeze, Hold, Schedule, StateHeader, SupplyChange, Transaction,
+};
 use async_graphql::SimpleObject;
+use base::types::Role;
 use linera_sdk::{
-    linera_base_types::{AccountOwner, Amount, ApplicationId},
-    views::{linera_views, MapView, RegisterView, RootView, SetView, ViewStorageContext},
+    linera_base_types::{AccountOwner, Amount, ApplicationId, CryptoHash},
+    views::{
+        linera_views::{self, views::ClonableView},
+        LogView, MapView, RegisterView, RootView, SetView, ViewStorageContext,
+    },
 };
 
-#[derive(RootView, SimpleObject)]
+/// The fields `StateQueryInterface` serves are skipped by GraphQL, queries read them
+/// through the interface instead
+#[derive(RootView, ClonableView, SimpleObject)]
 #[view(context = ViewStorageContext)]
 pub struct CreditState {
+    pub header: RegisterView<StateHeader>,
+    #[graphql(skip)]
     pub _initial_supply: RegisterView<Amount>,
+    #[graphql(skip)]
     pub _balance: RegisterView<Amount>,
     pub amount_alive_ms: RegisterView<u64>,
+    #[graphql(skip)]
     pub balances: MapView<AccountOwner, AgeAmounts>,
+    #[graphql(skip)]
     pub spendables: MapView<AccountOwner, Amount>,
+    #[graphql(skip)]
+    pub holds: MapView<String, Hold>,
+    /// Sum of the holds of each owner
+    #[graphql(skip)]
+    pub held: MapView<AccountOwner, Amount>,
     pub reward_callers: SetView<ApplicationId>,
     pub transfer_callers: SetView<ApplicationId>,
+    pub roles: MapView<AccountOwner, Vec<Role>>,
+    /// Accounts which can't send or receive transfers
+    #[graphql(skip)]
+    pub frozen: MapView<AccountOwner, Freeze>,
+    pub schedules: MapView<u64, Schedule>,
+    pub next_schedule_id: RegisterView<u64>,
+    pub config_changes: LogView<ConfigChange>,
+    #[graphql(skip)]
+    pub supply_history: LogView<SupplyChange>,
+    #[graphql(skip)]
+    pub transactions: LogView<Transaction>,
+    /// Positions in `transactions` of the transactions carrying each reference
+    #[graphql(skip)]
+    pub references: MapView<CryptoHash, Vec<u32>>,
+    /// Hash chained over the records of the open import, checked by its finalize record
+    #[graphql(skip)]
+    pub import_hash: RegisterView<String>,
 }
 
 pub mod adapter;
 pub mod errors;
+pub mod freezes;
+pub mod holds;
+pub mod invariants;
+pub mod ledger;
+pub mod migration;
+pub mod query_impl;
+pub mod schedules;
+pub mod snapshot;
 pub mod state_impl;
+pub mod transactions;
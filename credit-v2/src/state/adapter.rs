@@ -2,11 +2,13 @@ use std::{cell::RefCell, rc::Rc};
 
 use super::errors::StateError;
 use crate::{
-    instantiation_argument::InstantiationArgument, interfaces::state::StateInterface,
+    instantiation_argument::InstantiationArgument,
+    interfaces::state::StateInterface,
     state::CreditState,
+    types::{BatchEntryResult, Hold, RewardEntry, RewardExpiry, StateRecord, TransferEntry},
 };
 use async_trait::async_trait;
-use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, Timestamp};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, CryptoHash, Timestamp};
 
 pub struct StateAdapter {
     state: Rc<RefCell<CreditState>>,
@@ -34,8 +36,12 @@ impl StateInterface for StateAdapter {
         self.state.borrow().initial_supply()
     }
 
-    async fn balance(&self, owner: AccountOwner) -> Amount {
-        self.state.borrow().balance(owner).await
+    async fn alive_balance(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Amount, Self::Error> {
+        self.state.borrow().alive_balance(owner, now).await
     }
 
     async fn reward(
@@ -43,34 +49,197 @@ impl StateInterface for StateAdapter {
         owner: AccountOwner,
         amount: Amount,
         now: Timestamp,
+        expiry: Option<RewardExpiry>,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
     ) -> Result<(), Self::Error> {
-        self.state.borrow_mut().reward(owner, amount, now).await
+        self.state
+            .borrow_mut()
+            .reward(owner, amount, now, expiry, memo, reference)
+            .await
     }
 
-    async fn liquidate(&mut self, now: Timestamp) {
+    async fn liquidate(&mut self, now: Timestamp) -> Result<(), Self::Error> {
         self.state.borrow_mut().liquidate(now).await
     }
 
-    fn set_reward_callers(&mut self, application_ids: Vec<ApplicationId>) {
+    fn set_reward_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), Self::Error> {
         self.state.borrow_mut().set_reward_callers(application_ids)
     }
 
-    fn set_transfer_callers(&mut self, application_ids: Vec<ApplicationId>) {
+    fn set_transfer_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), Self::Error> {
         self.state
             .borrow_mut()
             .set_transfer_callers(application_ids)
     }
 
+    async fn is_transfer_caller(&self, application_id: ApplicationId) -> Result<bool, Self::Error> {
+        self.state.borrow().is_transfer_caller(application_id).await
+    }
+
     async fn transfer(
         &mut self,
         from: AccountOwner,
         to: AccountOwner,
         amount: Amount,
         now: Timestamp,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
     ) -> Result<(), Self::Error> {
         self.state
             .borrow_mut()
-            .transfer(from, to, amount, now)
+            .transfer(from, to, amount, now, memo, reference)
             .await
     }
+
+    async fn batch_reward(
+        &mut self,
+        entries: Vec<RewardEntry>,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, Self::Error> {
+        self.state.borrow_mut().batch_reward(entries, now).await
+    }
+
+    async fn batch_transfer(
+        &mut self,
+        from: AccountOwner,
+        entries: Vec<TransferEntry>,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, Self::Error> {
+        self.state
+            .borrow_mut()
+            .batch_transfer(from, entries, now)
+            .await
+    }
+
+    async fn hold(
+        &mut self,
+        hold_id: String,
+        owner: AccountOwner,
+        operator: AccountOwner,
+        amount: Amount,
+        expires_at: Timestamp,
+        now: Timestamp,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .hold(hold_id, owner, operator, amount, expires_at, now)
+            .await
+    }
+
+    async fn capture_hold(
+        &mut self,
+        hold_id: String,
+        operator: AccountOwner,
+        to: AccountOwner,
+    ) -> Result<Hold, Self::Error> {
+        self.state
+            .borrow_mut()
+            .capture_hold(hold_id, operator, to)
+            .await
+    }
+
+    async fn release_hold(
+        &mut self,
+        hold_id: String,
+        operator: AccountOwner,
+    ) -> Result<Hold, Self::Error> {
+        self.state
+            .borrow_mut()
+            .release_hold(hold_id, operator)
+            .await
+    }
+
+    fn create_schedule(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        interval_ms: u64,
+        count: u32,
+        start_at: Timestamp,
+    ) -> Result<u64, Self::Error> {
+        self.state
+            .borrow_mut()
+            .create_schedule(owner, amount, interval_ms, count, start_at)
+    }
+
+    async fn cancel_schedule(&mut self, schedule_id: u64) -> Result<(), Self::Error> {
+        self.state.borrow_mut().cancel_schedule(schedule_id).await
+    }
+
+    async fn process_schedules(
+        &mut self,
+        max: u32,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, Self::Error> {
+        self.state.borrow_mut().process_schedules(max, now).await
+    }
+
+    async fn burn(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), Self::Error> {
+        self.state.borrow_mut().burn(owner, amount, now).await
+    }
+
+    fn mint(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        self.state.borrow_mut().mint(owner, amount, now)
+    }
+
+    fn update_config(
+        &mut self,
+        amount_alive_ms: u64,
+        max_ttl_ms: u64,
+        changed_by: AccountOwner,
+        now: Timestamp,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .update_config(amount_alive_ms, max_ttl_ms, changed_by, now)
+    }
+
+    fn freeze(
+        &mut self,
+        owner: AccountOwner,
+        allow_rewards: bool,
+        frozen_by: AccountOwner,
+        now: Timestamp,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .freeze(owner, allow_rewards, frozen_by, now)
+    }
+
+    async fn unfreeze(&mut self, owner: AccountOwner) -> Result<(), Self::Error> {
+        self.state.borrow_mut().unfreeze(owner).await
+    }
+
+    async fn import_state(&mut self, records: Vec<StateRecord>) -> Result<(), Self::Error> {
+        self.state.borrow_mut().import_state(records).await
+    }
+
+    async fn import_snapshot(
+        &mut self,
+        records: Vec<StateRecord>,
+        hash: String,
+    ) -> Result<(), Self::Error> {
+        self.state.borrow_mut().import_snapshot(records, hash).await
+    }
+
+    fn abort_import(&mut self) -> Result<(), Self::Error> {
+        self.state.borrow_mut().abort_import()
+    }
 }
@@ -1,37 +1,154 @@
+use std::fmt;
+
+use linera_sdk::{
+    linera_base_types::{AccountOwner, Amount, Timestamp},
+    views::ViewError,
+};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// An error that can occur during the contract execution.
-#[derive(Debug, Error)]
+#[derive(Debug, Deserialize, Error, Serialize)]
 pub enum StateError {
-    /// Failed to deserialize BCS bytes
-    #[error("Failed to deserialize BCS bytes")]
-    BcsError(#[from] bcs::Error),
+    /// Failed to serialize BCS bytes
+    #[error("Failed to serialize BCS bytes: {0}")]
+    BcsError(String),
+
+    /// Failed to serialize JSON string
+    #[error("Failed to serialize JSON string: {0}")]
+    JsonError(String),
+
+    #[error("Insufficient balance of {owner}: required {required}, available {available}")]
+    InsufficientAccountBalance {
+        owner: AccountOwner,
+        required: Amount,
+        available: Amount,
+    },
+
+    #[error("Insufficient supply balance: required {required}, available {available}")]
+    InsufficientSupplyBalance { required: Amount, available: Amount },
+
+    #[error("No import in progress")]
+    NotImporting,
+
+    #[error("Import in progress")]
+    ImportInProgress,
+
+    #[error("State version {version} is newer than {supported}")]
+    UnsupportedStateVersion { version: u32, supported: u32 },
+
+    #[error("Import header on a state which has accounts")]
+    StateNotEmpty,
+
+    #[error("Account {0} already exists")]
+    AccountExists(AccountOwner),
+
+    #[error("Migration {field} mismatch: expected {expected}, actual {actual}")]
+    MigrationMismatch {
+        field: MigrationField,
+        expected: Amount,
+        actual: Amount,
+    },
+
+    #[error("Migration account count mismatch: expected {expected}, actual {actual}")]
+    AccountCountMismatch { expected: u32, actual: u32 },
+
+    #[error("Snapshot hash mismatch: expected {expected}, actual {actual}")]
+    SnapshotHashMismatch { expected: String, actual: String },
+
+    #[error("Snapshot finalize record without a state hash")]
+    MissingStateHash,
+
+    #[error("Expiry {expires_at} is not after {now}")]
+    InvalidExpiry {
+        expires_at: Timestamp,
+        now: Timestamp,
+    },
+
+    #[error("A never expiring reward must be given as `never: true`")]
+    InvalidNeverExpiry,
 
-    /// Failed to deserialize JSON string
-    #[error("Failed to deserialize JSON string")]
-    JsonError(#[from] serde_json::Error),
+    #[error("Invalid lot lifetime {ttl_ms} ms, maximum {max_ttl_ms} ms")]
+    InvalidTtl { ttl_ms: u64, max_ttl_ms: u64 },
 
-    #[error("NOT IMPLEMENTED")]
-    NotImplemented,
+    #[error("Initial supply must not be zero")]
+    InvalidInitialSupply,
 
-    #[error("Caller not allowed")]
-    CallerNotAllowed,
+    #[error("Invalid {decimals} decimals, maximum {max}")]
+    InvalidDecimals { decimals: u8, max: u32 },
 
-    #[error("Operation not allowed")]
-    OperationNotAllowed,
+    #[error("Invalid batch size {size}, expected 1 to {max}")]
+    InvalidBatchSize { size: usize, max: usize },
 
-    #[error("Cross-application sessions not supported")]
-    SessionsNotSupported,
+    #[error("Hold {0} already exists")]
+    HoldExists(String),
 
-    #[error("Insufficient account balance")]
-    InsufficientAccountBalance,
+    #[error("Hold {0} not found")]
+    HoldNotFound(String),
 
-    #[error("Invalid signer")]
-    InvalidSigner,
+    #[error("Hold {hold_id} is not operated by {operator}")]
+    NotHoldOperator {
+        hold_id: String,
+        operator: AccountOwner,
+    },
 
-    #[error("Invalid message id")]
-    InvalidMessageId,
+    #[error("Invalid schedule of {amount} every {interval_ms} ms, {count} times")]
+    InvalidSchedule {
+        amount: Amount,
+        interval_ms: u64,
+        count: u32,
+    },
+
+    #[error("Schedule {0} not found")]
+    ScheduleNotFound(u64),
+
+    #[error("Account {0} is frozen")]
+    FrozenAccount(AccountOwner),
+
+    #[error("Account {0} is not frozen")]
+    NotFrozen(AccountOwner),
+
+    #[error("Memo of {length} bytes exceeds {max} bytes")]
+    MemoTooLong { length: usize, max: usize },
+
+    #[error("View error: {0}")]
+    ViewError(String),
+}
+
+/// Total compared by a migration or snapshot import
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum MigrationField {
+    Spendable(AccountOwner),
+    TotalSpendable,
+    Supply,
+    HoldAmount(String),
+}
+
+impl fmt::Display for MigrationField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spendable(owner) => write!(f, "spendable of {owner}"),
+            Self::TotalSpendable => f.write_str("total spendable"),
+            Self::Supply => f.write_str("supply"),
+            Self::HoldAmount(hold_id) => write!(f, "amount of hold {hold_id}"),
+        }
+    }
+}
+
+impl From<bcs::Error> for StateError {
+    fn from(err: bcs::Error) -> Self {
+        Self::BcsError(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for StateError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::JsonError(err.to_string())
+    }
+}
 
-    #[error("View error")]
-    ViewError(#[from] linera_sdk::views::ViewError),
+impl From<ViewError> for StateError {
+    fn from(err: ViewError) -> Self {
+        Self::ViewError(err.to_string())
+    }
 }
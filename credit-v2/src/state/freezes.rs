@@ -0,0 +1,45 @@
+use super::{errors::StateError, CreditState};
+use crate::types::FreezeRecord;
+use linera_sdk::linera_base_types::AccountOwner;
+
+impl CreditState {
+    /// Fails when `owner` is frozen, whatever the freeze allows
+    pub(crate) async fn ensure_not_frozen(&self, owner: AccountOwner) -> Result<(), StateError> {
+        match self.frozen.contains_key(&owner).await? {
+            true => Err(StateError::FrozenAccount(owner)),
+            false => Ok(()),
+        }
+    }
+
+    /// Whether `owner` is frozen by a freeze which doesn't allow rewards
+    pub(crate) async fn rewards_frozen(&self, owner: &AccountOwner) -> Result<bool, StateError> {
+        Ok(self
+            .frozen
+            .get(owner)
+            .await?
+            .is_some_and(|freeze| !freeze.allow_rewards))
+    }
+
+    /// Frozen accounts in owner order
+    pub(crate) async fn frozen_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<FreezeRecord>, StateError> {
+        let mut records = Vec::new();
+        let mut index = 0;
+        self.frozen
+            .for_each_index_value_while(|owner, freeze| {
+                if index >= offset && records.len() < limit as usize {
+                    records.push(FreezeRecord {
+                        owner,
+                        freeze: freeze.into_owned(),
+                    });
+                }
+                index += 1;
+                Ok(records.len() < limit as usize)
+            })
+            .await?;
+        Ok(records)
+    }
+}
@@ -0,0 +1,182 @@
+use super::{
+    errors::{MigrationField, StateError},
+    CreditState,
+};
+use crate::types::{AgeAmount, AgeAmounts, Hold};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
+
+impl CreditState {
+    /// Sum of the credit locked by all holds
+    pub(crate) async fn total_held(&self) -> Result<Amount, StateError> {
+        let mut total = Amount::ZERO;
+        self.holds
+            .for_each_index_value(|_, hold| {
+                total = total.saturating_add(hold.amount);
+                Ok(())
+            })
+            .await?;
+        Ok(total)
+    }
+
+    /// Moves `amount` of the lots of `owner` which expire first into a new hold
+    pub(crate) async fn place_hold(
+        &mut self,
+        hold_id: String,
+        owner: AccountOwner,
+        operator: AccountOwner,
+        amount: Amount,
+        expires_at: Timestamp,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        if expires_at <= now {
+            return Err(StateError::InvalidExpiry { expires_at, now });
+        }
+        if self.holds.contains_key(&hold_id).await? {
+            return Err(StateError::HoldExists(hold_id));
+        }
+        self.ensure_not_frozen(owner).await?;
+
+        let spendable = self.spendables.get(&owner).await?.unwrap_or_default();
+        let insufficient = StateError::InsufficientAccountBalance {
+            owner,
+            required: amount,
+            available: spendable,
+        };
+        if spendable < amount {
+            return Err(insufficient);
+        }
+        let Some(mut amounts) = self.balances.get(&owner).await? else {
+            return Err(insufficient);
+        };
+        self.spendables
+            .insert(&owner, spendable.saturating_sub(amount))?;
+
+        let lots = amounts.take(amount);
+        self.balances.insert(&owner, amounts)?;
+
+        self.add_held(owner, amount).await?;
+        self.holds.insert(
+            &hold_id,
+            Hold {
+                owner,
+                operator,
+                amount,
+                lots,
+                expires_at,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Removes a hold and credits its lots to `to`, or back to the holder when `to` is none.
+    ///
+    /// Only the operator which placed the hold may settle it, `operator` is none when the
+    /// hold is released by the liquidation.
+    pub(crate) async fn settle_hold(
+        &mut self,
+        hold_id: &String,
+        operator: Option<AccountOwner>,
+        to: Option<AccountOwner>,
+    ) -> Result<Hold, StateError> {
+        self.ensure_not_importing()?;
+        let hold = self
+            .holds
+            .get(hold_id)
+            .await?
+            .ok_or_else(|| StateError::HoldNotFound(hold_id.clone()))?;
+        if let Some(operator) = operator.filter(|operator| *operator != hold.operator) {
+            return Err(StateError::NotHoldOperator {
+                hold_id: hold_id.clone(),
+                operator,
+            });
+        }
+        self.holds.remove(hold_id)?;
+
+        let held = self
+            .held
+            .get(&hold.owner)
+            .await?
+            .unwrap_or_default()
+            .saturating_sub(hold.amount);
+        if held == Amount::ZERO {
+            self.held.remove(&hold.owner)?;
+        } else {
+            self.held.insert(&hold.owner, held)?;
+        }
+
+        self.credit_lots(to.unwrap_or(hold.owner), hold.lots.clone())
+            .await?;
+        Ok(hold)
+    }
+
+    /// Releases the holds which expired before `now` back to their holders
+    pub(crate) async fn release_expired_holds(&mut self, now: Timestamp) -> Result<(), StateError> {
+        let mut expired = Vec::new();
+        self.holds
+            .for_each_index_value(|hold_id, hold| {
+                if hold.expires_at <= now {
+                    expired.push(hold_id);
+                }
+                Ok(())
+            })
+            .await?;
+
+        for hold_id in expired {
+            self.settle_hold(&hold_id, None, None).await?;
+        }
+        Ok(())
+    }
+
+    async fn add_held(&mut self, owner: AccountOwner, amount: Amount) -> Result<(), StateError> {
+        let held = self.held.get(&owner).await?.unwrap_or_default();
+        self.held.insert(&owner, held.saturating_add(amount))?;
+        Ok(())
+    }
+
+    async fn credit_lots(
+        &mut self,
+        owner: AccountOwner,
+        lots: Vec<AgeAmount>,
+    ) -> Result<(), StateError> {
+        let amount = lots
+            .iter()
+            .fold(Amount::ZERO, |sum, lot| sum.saturating_add(lot.amount));
+
+        let mut amounts = self.balances.get(&owner).await?.unwrap_or(AgeAmounts {
+            amounts: Vec::new(),
+        });
+        amounts.amounts.extend(lots);
+        self.balances.insert(&owner, amounts)?;
+
+        let spendable = self.spendables.get(&owner).await?.unwrap_or_default();
+        self.spendables
+            .insert(&owner, spendable.saturating_add(amount))?;
+        Ok(())
+    }
+
+    /// Restores an exported hold, its lots are already out of the holder's balances
+    pub(crate) async fn restore_hold(
+        &mut self,
+        hold_id: String,
+        hold: Hold,
+    ) -> Result<(), StateError> {
+        let amount = hold
+            .lots
+            .iter()
+            .fold(Amount::ZERO, |sum, lot| sum.saturating_add(lot.amount));
+        if amount != hold.amount {
+            return Err(StateError::MigrationMismatch {
+                field: MigrationField::HoldAmount(hold_id),
+                expected: hold.amount,
+                actual: amount,
+            });
+        }
+        if self.holds.contains_key(&hold_id).await? {
+            return Err(StateError::HoldExists(hold_id));
+        }
+        self.add_held(hold.owner, hold.amount).await?;
+        self.holds.insert(&hold_id, hold)?;
+        Ok(())
+    }
+}
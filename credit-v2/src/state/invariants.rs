@@ -0,0 +1,54 @@
+use std::collections::BTreeSet;
+
+use super::{errors::StateError, CreditState};
+use crate::types::{Invariant, InvariantViolation};
+use linera_sdk::linera_base_types::Amount;
+
+impl CreditState {
+    /// Checks that no credit is created or lost, returning every violated invariant
+    pub async fn check_invariants(&self) -> Result<Vec<InvariantViolation>, StateError> {
+        let mut violations = Vec::new();
+
+        let mut owners = self.spendables.indices().await?;
+        owners.extend(self.balances.indices().await?);
+        let owners = owners.into_iter().collect::<BTreeSet<_>>();
+
+        let mut spendables = Amount::ZERO;
+        for owner in owners {
+            let spendable = self.spendables.get(&owner).await?.unwrap_or_default();
+            let balance = self
+                .balances
+                .get(&owner)
+                .await?
+                .map(|amounts| amounts.sum())
+                .unwrap_or_default();
+
+            if spendable != balance {
+                violations.push(InvariantViolation {
+                    invariant: Invariant::SpendableBalance,
+                    owners: vec![owner],
+                    expected: balance,
+                    actual: spendable,
+                });
+            }
+            spendables = spendables.saturating_add(spendable);
+        }
+
+        let initial_supply = *self._initial_supply.get();
+        let supply = self
+            ._balance
+            .get()
+            .saturating_add(spendables)
+            .saturating_add(self.total_held().await?);
+        if initial_supply != supply {
+            violations.push(InvariantViolation {
+                invariant: Invariant::SupplyConservation,
+                owners: Vec::new(),
+                expected: initial_supply,
+                actual: supply,
+            });
+        }
+
+        Ok(violations)
+    }
+}
@@ -0,0 +1,48 @@
+use super::{errors::StateError, CreditState};
+use crate::{
+    instantiation_argument::InstantiationArgument,
+    interfaces::state::{LedgerInterface, StateInterface},
+};
+use async_trait::async_trait;
+use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
+
+/// Rewards and transfers without the expiry, memo and reference credit-v1 doesn't have
+#[async_trait(?Send)]
+impl LedgerInterface for CreditState {
+    type Error = StateError;
+
+    fn instantiate(&mut self, argument: InstantiationArgument) {
+        StateInterface::instantiate(self, argument)
+    }
+
+    async fn alive_balance(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Amount, StateError> {
+        self.balance_at(owner, now).await
+    }
+
+    async fn reward(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        StateInterface::reward(self, owner, amount, now, None, None, None).await
+    }
+
+    async fn transfer(
+        &mut self,
+        from: AccountOwner,
+        to: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        StateInterface::transfer(self, from, to, amount, now, None, None).await
+    }
+
+    async fn liquidate(&mut self, now: Timestamp) -> Result<(), StateError> {
+        StateInterface::liquidate(self, now).await
+    }
+}
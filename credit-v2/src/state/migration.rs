@@ -0,0 +1,225 @@
+use super::{
+    errors::{MigrationField, StateError},
+    snapshot::chain_hash,
+    CreditState,
+};
+use crate::types::{
+    AccountRecord, AgeAmounts, FinalizeRecord, HeaderRecord, StateHeader, StateRecord,
+    STATE_VERSION,
+};
+use linera_sdk::{linera_base_types::Amount, views::View};
+
+impl CreditState {
+    /// Applies records exported by a previous state version.
+    ///
+    /// Migrations only come from older versions, while a snapshot may also come from
+    /// this version, so `max_version` is the newest version the header may carry.
+    ///
+    /// A header record opens the import on an empty state, the other records may then
+    /// arrive over several operations, and the finalize record closes it after checking
+    /// the imported totals and the hash chained over the imported records.
+    pub(crate) async fn apply_state_records(
+        &mut self,
+        records: Vec<StateRecord>,
+        max_version: u32,
+    ) -> Result<(), StateError> {
+        for record in records {
+            let import_hash = match &record {
+                StateRecord::Finalize(_) => None,
+                StateRecord::Header(_) => Some(chain_hash("", &record)?),
+                _ => Some(chain_hash(self.import_hash.get(), &record)?),
+            };
+            match record {
+                StateRecord::Header(header) => self.import_header(header, max_version).await?,
+                StateRecord::Account(account) => self.import_account(account).await?,
+                StateRecord::RewardCallers(application_ids) => {
+                    self.ensure_importing()?;
+                    for application_id in application_ids {
+                        self.reward_callers.insert(&application_id)?;
+                    }
+                }
+                StateRecord::TransferCallers(application_ids) => {
+                    self.ensure_importing()?;
+                    for application_id in application_ids {
+                        self.transfer_callers.insert(&application_id)?;
+                    }
+                }
+                StateRecord::Hold(record) => {
+                    self.ensure_importing()?;
+                    self.restore_hold(record.hold_id, record.hold).await?;
+                }
+                StateRecord::Finalize(finalize) => self.finalize_import(finalize).await?,
+                StateRecord::Freeze(record) => {
+                    self.ensure_importing()?;
+                    self.frozen.insert(&record.owner, record.freeze)?;
+                }
+                StateRecord::Schedule(record) => {
+                    self.ensure_importing()?;
+                    self.schedules
+                        .insert(&record.schedule_id, record.schedule)?;
+                }
+                StateRecord::NextScheduleId(schedule_id) => {
+                    self.ensure_importing()?;
+                    self.next_schedule_id.set(schedule_id);
+                }
+                StateRecord::Roles(record) => {
+                    self.ensure_importing()?;
+                    self.roles.insert(&record.owner, record.roles)?;
+                }
+                StateRecord::SupplyChange(change) => {
+                    self.ensure_importing()?;
+                    self.supply_history.push(change);
+                }
+                StateRecord::ConfigChange(change) => {
+                    self.ensure_importing()?;
+                    self.config_changes.push(change);
+                }
+                StateRecord::Transaction(transaction) => {
+                    self.ensure_importing()?;
+                    self.record_transaction(transaction).await?;
+                }
+            }
+            if let Some(import_hash) = import_hash {
+                self.import_hash.set(import_hash);
+            }
+        }
+        Ok(())
+    }
+
+    fn ensure_importing(&self) -> Result<(), StateError> {
+        match self.header.get().importing {
+            true => Ok(()),
+            false => Err(StateError::NotImporting),
+        }
+    }
+
+    /// Rejects changes to the accounts until an open import is finalized or aborted
+    pub(crate) fn ensure_not_importing(&self) -> Result<(), StateError> {
+        match self.header.get().importing {
+            true => Err(StateError::ImportInProgress),
+            false => Ok(()),
+        }
+    }
+
+    /// Drops every record of an open import, leaving an empty state a new import can
+    /// start on. Imported roles stay, the admin aborting the import may hold one of them.
+    pub(crate) fn abort_import(&mut self) -> Result<(), StateError> {
+        self.ensure_importing()?;
+
+        self._initial_supply.set(Amount::ZERO);
+        self._balance.set(Amount::ZERO);
+        View::clear(&mut self.balances);
+        View::clear(&mut self.spendables);
+        View::clear(&mut self.holds);
+        View::clear(&mut self.held);
+        View::clear(&mut self.reward_callers);
+        View::clear(&mut self.transfer_callers);
+        View::clear(&mut self.frozen);
+        View::clear(&mut self.schedules);
+        self.next_schedule_id.set(0);
+        View::clear(&mut self.supply_history);
+        View::clear(&mut self.config_changes);
+        View::clear(&mut self.transactions);
+        View::clear(&mut self.references);
+        self.import_hash.set(String::new());
+        self.header.set(StateHeader {
+            version: STATE_VERSION,
+            importing: false,
+        });
+        Ok(())
+    }
+
+    async fn import_header(
+        &mut self,
+        header: HeaderRecord,
+        max_version: u32,
+    ) -> Result<(), StateError> {
+        if header.version > max_version {
+            return Err(StateError::UnsupportedStateVersion {
+                version: header.version,
+                supported: max_version,
+            });
+        }
+        if self.balances.count().await? > 0 {
+            return Err(StateError::StateNotEmpty);
+        }
+        self._initial_supply.set(header.initial_supply);
+        self._balance.set(header.supply_balance);
+        self.amount_alive_ms.set(header.amount_alive_ms);
+        self.header.set(StateHeader {
+            version: STATE_VERSION,
+            importing: true,
+        });
+        Ok(())
+    }
+
+    async fn import_account(&mut self, account: AccountRecord) -> Result<(), StateError> {
+        self.ensure_importing()?;
+        if self.balances.contains_key(&account.owner).await? {
+            return Err(StateError::AccountExists(account.owner));
+        }
+        let amounts = AgeAmounts {
+            amounts: account.amounts,
+        };
+        if amounts.sum() != account.spendable {
+            return Err(StateError::MigrationMismatch {
+                field: MigrationField::Spendable(account.owner),
+                expected: account.spendable,
+                actual: amounts.sum(),
+            });
+        }
+        self.balances.insert(&account.owner, amounts)?;
+        self.spendables.insert(&account.owner, account.spendable)?;
+        Ok(())
+    }
+
+    async fn finalize_import(&mut self, finalize: FinalizeRecord) -> Result<(), StateError> {
+        self.ensure_importing()?;
+
+        let accounts = self.balances.count().await?;
+        if accounts != finalize.accounts as usize {
+            return Err(StateError::AccountCountMismatch {
+                expected: finalize.accounts,
+                actual: accounts as u32,
+            });
+        }
+        let mut total_spendable = Amount::ZERO;
+        self.spendables
+            .for_each_index_value(|_, spendable| {
+                total_spendable = total_spendable.saturating_add(*spendable);
+                Ok(())
+            })
+            .await?;
+        if total_spendable != finalize.total_spendable {
+            return Err(StateError::MigrationMismatch {
+                field: MigrationField::TotalSpendable,
+                expected: finalize.total_spendable,
+                actual: total_spendable,
+            });
+        }
+        let supply = self
+            ._balance
+            .get()
+            .saturating_add(total_spendable)
+            .saturating_add(self.total_held().await?);
+        if *self._initial_supply.get() != supply {
+            return Err(StateError::MigrationMismatch {
+                field: MigrationField::Supply,
+                expected: *self._initial_supply.get(),
+                actual: supply,
+            });
+        }
+
+        if let Some(state_hash) = finalize.state_hash {
+            if state_hash != *self.import_hash.get() {
+                return Err(StateError::SnapshotHashMismatch {
+                    expected: state_hash,
+                    actual: self.import_hash.get().clone(),
+                });
+            }
+        }
+
+        self.header.get_mut().importing = false;
+        Ok(())
+    }
+}
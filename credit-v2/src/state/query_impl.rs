@@ -0,0 +1,98 @@
+use crate::interfaces::state::StateQueryInterface;
+use crate::state::{errors::StateError, CreditState};
+use crate::types::{
+    AgeAmount, FreezeRecord, Hold, InvariantViolation, SnapshotPage, SupplyChange, Transaction,
+};
+use async_trait::async_trait;
+use linera_sdk::linera_base_types::{AccountOwner, Amount, CryptoHash, Timestamp};
+
+#[async_trait]
+impl StateQueryInterface for CreditState {
+    type Error = StateError;
+
+    fn initial_supply(&self) -> Amount {
+        *self._initial_supply.get()
+    }
+
+    fn supply_balance(&self) -> Amount {
+        *self._balance.get()
+    }
+
+    async fn balance(&self, owner: AccountOwner, now: Timestamp) -> Result<Amount, StateError> {
+        self.balance_at(owner, now).await
+    }
+
+    async fn alive_amounts(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Vec<AgeAmount>, StateError> {
+        Ok(self
+            .balances
+            .get(&owner)
+            .await?
+            .map(|amounts| {
+                amounts
+                    .amounts
+                    .into_iter()
+                    .filter(|amount| !amount.is_expired(now))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn check_invariants(&self) -> Result<Vec<InvariantViolation>, StateError> {
+        CreditState::check_invariants(self).await
+    }
+
+    async fn held_balance(&self, owner: AccountOwner) -> Result<Amount, StateError> {
+        Ok(self.held.get(&owner).await?.unwrap_or_default())
+    }
+
+    async fn hold(&self, hold_id: String) -> Result<Option<Hold>, StateError> {
+        Ok(self.holds.get(&hold_id).await?)
+    }
+
+    async fn transactions(&self, offset: u32, limit: u32) -> Result<Vec<Transaction>, StateError> {
+        let count = self.transactions.count();
+        let start = (offset as usize).min(count);
+        let end = count.min(start + limit as usize);
+        Ok(self.transactions.read(start..end).await?)
+    }
+
+    async fn transactions_by_reference(
+        &self,
+        reference: CryptoHash,
+    ) -> Result<Vec<Transaction>, StateError> {
+        let mut transactions = Vec::new();
+        for position in self.references.get(&reference).await?.unwrap_or_default() {
+            if let Some(transaction) = self.transactions.get(position as usize).await? {
+                transactions.push(transaction);
+            }
+        }
+        Ok(transactions)
+    }
+
+    async fn supply_history(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SupplyChange>, StateError> {
+        let count = self.supply_history.count();
+        let start = (offset as usize).min(count);
+        let end = count.min(start + limit as usize);
+        Ok(self.supply_history.read(start..end).await?)
+    }
+
+    async fn snapshot(&self, offset: u32, limit: u32) -> Result<SnapshotPage, StateError> {
+        self.snapshot_page(offset, limit).await
+    }
+
+    async fn frozen_accounts(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<FreezeRecord>, StateError> {
+        self.frozen_page(offset, limit).await
+    }
+}
@@ -0,0 +1,89 @@
+use super::{errors::StateError, CreditState};
+use crate::{
+    interfaces::state::StateInterface,
+    types::{AgeAmount, BatchEntryResult, Schedule},
+};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
+
+impl CreditState {
+    pub(crate) fn add_schedule(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        interval_ms: u64,
+        count: u32,
+        start_at: Timestamp,
+    ) -> Result<u64, StateError> {
+        self.ensure_not_importing()?;
+        if amount == Amount::ZERO || interval_ms == 0 || count == 0 {
+            return Err(StateError::InvalidSchedule {
+                amount,
+                interval_ms,
+                count,
+            });
+        }
+        let schedule_id = *self.next_schedule_id.get();
+        self.schedules.insert(
+            &schedule_id,
+            Schedule {
+                owner,
+                amount,
+                interval_ms,
+                remaining: count,
+                next_at: start_at,
+            },
+        )?;
+        self.next_schedule_id.set(schedule_id + 1);
+        Ok(schedule_id)
+    }
+
+    /// Issues at most `max` due installments, one per schedule and call.
+    ///
+    /// A schedule which the supply balance can't cover, or whose owner is frozen without
+    /// rewards, stays due for a later call and doesn't count toward `max`.
+    pub(crate) async fn issue_due_schedules(
+        &mut self,
+        max: u32,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, StateError> {
+        self.ensure_not_importing()?;
+        let mut due = Vec::new();
+        self.schedules
+            .for_each_index_value(|schedule_id, schedule| {
+                if schedule.next_at <= now {
+                    due.push((schedule_id, schedule.into_owned()));
+                }
+                Ok(())
+            })
+            .await?;
+
+        let mut results = Vec::new();
+        for (schedule_id, mut schedule) in due {
+            if results.len() >= max as usize {
+                break;
+            }
+            if *self._balance.get() < schedule.amount
+                || self.rewards_frozen(&schedule.owner).await?
+            {
+                continue;
+            }
+            self.reward(schedule.owner, schedule.amount, now, None, None, None)
+                .await?;
+
+            schedule.remaining -= 1;
+            schedule.next_at = AgeAmount::expired_after(schedule.next_at, schedule.interval_ms);
+            if schedule.remaining == 0 {
+                self.schedules.remove(&schedule_id)?;
+            } else {
+                self.schedules.insert(&schedule_id, schedule.clone())?;
+            }
+
+            results.push(BatchEntryResult {
+                owner: schedule.owner,
+                amount: schedule.amount,
+                balance: self.balance_at(schedule.owner, now).await?,
+            });
+        }
+        Ok(results)
+    }
+}
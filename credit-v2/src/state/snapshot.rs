@@ -0,0 +1,175 @@
+use sha3::{Digest, Sha3_256};
+
+use super::{errors::StateError, CreditState};
+use crate::types::{
+    AccountRecord, AgeAmounts, FinalizeRecord, FreezeRecord, HeaderRecord, HoldRecord, RolesRecord,
+    ScheduleRecord, SnapshotPage, StateRecord, STATE_VERSION,
+};
+use linera_sdk::linera_base_types::{AccountOwner, Amount};
+
+/// Upper bound of the accounts and transactions returned by one snapshot page
+pub const MAX_SNAPSHOT_PAGE: u32 = 100;
+
+/// Hex encoded SHA3-256 of the BCS encoded records
+pub fn records_hash(records: &[StateRecord]) -> Result<String, StateError> {
+    let bytes = bcs::to_bytes(records)?;
+    Ok(hex::encode(Sha3_256::digest(&bytes)))
+}
+
+/// Folds `record` into the hash of the records before it, starting from an empty
+/// `previous`. The result doesn't depend on how the records are split into pages.
+pub fn chain_hash(previous: &str, record: &StateRecord) -> Result<String, StateError> {
+    let mut hasher = Sha3_256::new();
+    hasher.update(previous.as_bytes());
+    hasher.update(bcs::to_bytes(record)?);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+impl CreditState {
+    /// Builds one page of the state snapshot with the same records as a migration.
+    ///
+    /// The first page carries the settings, callers, holds, freezes, schedules, roles and
+    /// the supply and config history. The pages then walk the accounts in owner order and
+    /// the transactions in log order, so the same state always gives the same pages. The
+    /// last page closes with the totals and the hash chained over every record.
+    pub(crate) async fn snapshot_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<SnapshotPage, StateError> {
+        let mut records = Vec::new();
+        if offset == 0 {
+            records.extend(self.snapshot_head().await?);
+        }
+
+        let owners = self.balances.indices().await?;
+        let total = owners.len() + self.transactions.count();
+        let offset = (offset as usize).min(total);
+        let end = total.min(offset + limit.min(MAX_SNAPSHOT_PAGE) as usize);
+        records.extend(self.snapshot_entries(&owners, offset, end).await?);
+
+        let next_offset = if end < total {
+            Some(end as u32)
+        } else {
+            let mut state_hash = String::new();
+            let mut all = self.snapshot_head().await?;
+            all.extend(self.snapshot_entries(&owners, 0, total).await?);
+            for record in &all {
+                state_hash = chain_hash(&state_hash, record)?;
+            }
+
+            let mut total_spendable = Amount::ZERO;
+            for owner in &owners {
+                let spendable = self.spendables.get(owner).await?.unwrap_or_default();
+                total_spendable = total_spendable.saturating_add(spendable);
+            }
+            records.push(StateRecord::Finalize(FinalizeRecord {
+                accounts: owners.len() as u32,
+                total_spendable,
+                state_hash: Some(state_hash),
+            }));
+            None
+        };
+
+        Ok(SnapshotPage {
+            hash: records_hash(&records)?,
+            bcs: hex::encode(bcs::to_bytes(&records)?),
+            records: serde_json::to_string(&records)?,
+            next_offset,
+        })
+    }
+
+    /// Records of the first page, everything but the accounts and transactions
+    async fn snapshot_head(&self) -> Result<Vec<StateRecord>, StateError> {
+        let mut records = vec![
+            StateRecord::Header(HeaderRecord {
+                version: STATE_VERSION,
+                initial_supply: *self._initial_supply.get(),
+                supply_balance: *self._balance.get(),
+                amount_alive_ms: *self.amount_alive_ms.get(),
+            }),
+            StateRecord::RewardCallers(self.reward_callers.indices().await?),
+            StateRecord::TransferCallers(self.transfer_callers.indices().await?),
+        ];
+        self.holds
+            .for_each_index_value(|hold_id, hold| {
+                records.push(StateRecord::Hold(HoldRecord {
+                    hold_id,
+                    hold: hold.into_owned(),
+                }));
+                Ok(())
+            })
+            .await?;
+        self.frozen
+            .for_each_index_value(|owner, freeze| {
+                records.push(StateRecord::Freeze(FreezeRecord {
+                    owner,
+                    freeze: freeze.into_owned(),
+                }));
+                Ok(())
+            })
+            .await?;
+        self.schedules
+            .for_each_index_value(|schedule_id, schedule| {
+                records.push(StateRecord::Schedule(ScheduleRecord {
+                    schedule_id,
+                    schedule: schedule.into_owned(),
+                }));
+                Ok(())
+            })
+            .await?;
+        records.push(StateRecord::NextScheduleId(*self.next_schedule_id.get()));
+        self.roles
+            .for_each_index_value(|owner, roles| {
+                records.push(StateRecord::Roles(RolesRecord {
+                    owner,
+                    roles: roles.into_owned(),
+                }));
+                Ok(())
+            })
+            .await?;
+        records.extend(
+            self.supply_history
+                .read(..)
+                .await?
+                .into_iter()
+                .map(StateRecord::SupplyChange),
+        );
+        records.extend(
+            self.config_changes
+                .read(..)
+                .await?
+                .into_iter()
+                .map(StateRecord::ConfigChange),
+        );
+        Ok(records)
+    }
+
+    /// Records of the entries `start..end`, the accounts of `owners` then the transactions
+    async fn snapshot_entries(
+        &self,
+        owners: &[AccountOwner],
+        start: usize,
+        end: usize,
+    ) -> Result<Vec<StateRecord>, StateError> {
+        let mut records = Vec::new();
+        for owner in &owners[start.min(owners.len())..end.min(owners.len())] {
+            let amounts = self.balances.get(owner).await?.unwrap_or(AgeAmounts {
+                amounts: Vec::new(),
+            });
+            records.push(StateRecord::Account(AccountRecord {
+                owner: *owner,
+                amounts: amounts.amounts,
+                spendable: self.spendables.get(owner).await?.unwrap_or_default(),
+            }));
+        }
+        if end > owners.len() {
+            let transactions = self
+                .transactions
+                .read(start.max(owners.len()) - owners.len()..end - owners.len())
+                .await?;
+            records.extend(transactions.into_iter().map(StateRecord::Transaction));
+        }
+        Ok(records)
+    }
+}
@@ -1,25 +1,34 @@
-use std::cmp::Ordering;
-
 use crate::interfaces::state::StateInterface;
-use crate::state::{errors::StateError, CreditState};
+use crate::state::{
+    errors::StateError, snapshot::records_hash, transactions::check_memo, CreditState,
+};
 use crate::{
     instantiation_argument::InstantiationArgument,
-    types::{AgeAmount, AgeAmounts},
+    types::{
+        AgeAmount, AgeAmounts, BatchEntryResult, ConfigChange, FinalizeRecord, Freeze, Hold,
+        RewardEntry, RewardExpiry, StateHeader, StateRecord, SupplyChange, SupplyChangeKind,
+        Transaction, TransactionKind, TransferEntry, STATE_VERSION,
+    },
 };
 use async_trait::async_trait;
-use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, Timestamp};
+use base::{interfaces::access_control::RoleStore, types::Role};
+use linera_sdk::{
+    linera_base_types::{AccountOwner, Amount, ApplicationId, CryptoHash, Timestamp},
+    views::ViewError,
+};
 
 #[async_trait(?Send)]
 impl StateInterface for CreditState {
     type Error = StateError;
 
-    fn instantiate(&mut self, mut argument: InstantiationArgument) {
-        if argument.initial_supply.eq(&Amount::ZERO) {
-            argument.initial_supply = Amount::from_tokens(100000000);
-        }
+    fn instantiate(&mut self, argument: InstantiationArgument) {
         self._initial_supply.set(argument.initial_supply);
         self._balance.set(argument.initial_supply);
         self.amount_alive_ms.set(argument.amount_alive_ms);
+        self.header.set(StateHeader {
+            version: STATE_VERSION,
+            importing: false,
+        });
     }
 
     fn instantiation_argument(&self) -> InstantiationArgument {
@@ -33,8 +42,12 @@ impl StateInterface for CreditState {
         *self._initial_supply.get()
     }
 
-    async fn balance(&self, owner: AccountOwner) -> Amount {
-        self.balances.get(&owner).await.unwrap().unwrap().sum()
+    async fn alive_balance(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Amount, StateError> {
+        self.balance_at(owner, now).await
     }
 
     async fn reward(
@@ -42,98 +55,94 @@ impl StateInterface for CreditState {
         owner: AccountOwner,
         amount: Amount,
         now: Timestamp,
+        expiry: Option<RewardExpiry>,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
     ) -> Result<(), StateError> {
-        match self.spendables.get(&owner).await {
-            Ok(Some(spendable)) => {
-                self.spendables
-                    .insert(&owner, spendable.saturating_add(amount))
-                    .unwrap();
-            }
-            _ => {
-                self.spendables.insert(&owner, amount).unwrap();
-            }
+        check_memo(&memo)?;
+        self.ensure_not_importing()?;
+        if self.rewards_frozen(&owner).await? {
+            return Err(StateError::FrozenAccount(owner));
         }
 
-        match self._balance.get().cmp(&amount) {
-            Ordering::Less => {
-                log::error!(
-                    "Here we should correct: supply balance {} reward amount {}",
-                    self._balance.get(),
-                    amount
-                );
-                // return Err(StateError::InsufficientSupplyBalance)
-            }
-            _ => {}
+        let expired = match expiry {
+            Some(RewardExpiry::Never(false)) => return Err(StateError::InvalidNeverExpiry),
+            Some(expiry) => expiry.expired(now),
+            None => AgeAmount::expired_after(now, *self.amount_alive_ms.get()),
+        };
+        if expired <= now {
+            return Err(StateError::InvalidExpiry {
+                expires_at: expired,
+                now,
+            });
         }
 
-        self._balance
-            .set(self._balance.get().saturating_sub(amount));
-
-        match self.balances.get(&owner).await {
-            Ok(Some(mut amounts)) => {
-                amounts.amounts.push(AgeAmount {
-                    amount,
-                    expired: Timestamp::from(
-                        now.micros().saturating_add(*self.amount_alive_ms.get()),
-                    ),
-                });
-                match self.balances.insert(&owner, amounts) {
-                    Ok(_) => Ok(()),
-                    Err(err) => Err(StateError::ViewError(err)),
-                }
-            }
-            _ => match self.balances.insert(
-                &owner,
-                AgeAmounts {
-                    amounts: vec![AgeAmount {
-                        amount,
-                        expired: Timestamp::from(
-                            now.micros().saturating_add(*self.amount_alive_ms.get()),
-                        ),
-                    }],
-                },
-            ) {
-                Ok(_) => Ok(()),
-                Err(err) => Err(StateError::ViewError(err)),
-            },
+        let balance = *self._balance.get();
+        if balance < amount {
+            return Err(StateError::InsufficientSupplyBalance {
+                required: amount,
+                available: balance,
+            });
         }
+
+        let spendable = self.spendables.get(&owner).await?.unwrap_or_default();
+        self.spendables
+            .insert(&owner, spendable.saturating_add(amount))?;
+        self._balance.set(balance.saturating_sub(amount));
+
+        let mut amounts = self.balances.get(&owner).await?.unwrap_or(AgeAmounts {
+            amounts: Vec::new(),
+        });
+        amounts.amounts.push(AgeAmount { amount, expired });
+        self.balances.insert(&owner, amounts)?;
+
+        self.record_transaction(Transaction {
+            kind: TransactionKind::Reward,
+            from: None,
+            to: owner,
+            amount,
+            memo,
+            reference,
+            timestamp: now,
+        })
+        .await
     }
 
-    async fn liquidate(&mut self, now: Timestamp) {
-        let owners = self.balances.indices().await.unwrap();
+    async fn liquidate(&mut self, now: Timestamp) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        self.release_expired_holds(now).await?;
+
+        let owners = self.balances.indices().await?;
         for owner in owners {
-            let mut amounts = match self.balances.get(&owner).await {
-                Ok(Some(amounts)) => amounts,
-                _ => continue,
-            };
-            let mut spendable = match self.spendables.get(&owner).await {
-                Ok(Some(spendable)) => spendable,
-                _ => continue,
-            };
-            amounts.amounts.retain(|amount| {
-                let expired = now.micros() > amount.expired.micros();
-                if expired {
-                    self._balance
-                        .set(self._balance.get().saturating_add(amount.amount));
-                    spendable = spendable.saturating_sub(amount.amount);
-                }
-                !expired
-            });
-            self.spendables.insert(&owner, spendable).unwrap();
-            self.balances.insert(&owner, amounts).unwrap();
+            self.liquidate_owner(owner, now).await?;
         }
+        Ok(())
     }
 
-    fn set_reward_callers(&mut self, application_ids: Vec<ApplicationId>) {
-        application_ids
-            .iter()
-            .for_each(|application_id| self.reward_callers.insert(application_id).unwrap())
+    fn set_reward_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        for application_id in &application_ids {
+            self.reward_callers.insert(application_id)?;
+        }
+        Ok(())
+    }
+
+    fn set_transfer_callers(
+        &mut self,
+        application_ids: Vec<ApplicationId>,
+    ) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        for application_id in &application_ids {
+            self.transfer_callers.insert(application_id)?;
+        }
+        Ok(())
     }
 
-    fn set_transfer_callers(&mut self, application_ids: Vec<ApplicationId>) {
-        application_ids
-            .iter()
-            .for_each(|application_id| self.transfer_callers.insert(application_id).unwrap())
+    async fn is_transfer_caller(&self, application_id: ApplicationId) -> Result<bool, StateError> {
+        Ok(self.transfer_callers.contains(&application_id).await?)
     }
 
     async fn transfer(
@@ -142,79 +151,374 @@ impl StateInterface for CreditState {
         to: AccountOwner,
         amount: Amount,
         now: Timestamp,
+        memo: Option<String>,
+        reference: Option<CryptoHash>,
     ) -> Result<(), StateError> {
-        match self.spendables.get(&from).await {
-            Ok(Some(spendable)) => match spendable.cmp(&amount) {
-                Ordering::Less => Err(StateError::InsufficientAccountBalance),
-                _ => {
-                    self.spendables
-                        .insert(&from, spendable.saturating_sub(amount))?;
-                    let mut amounts = self.balances.get(&from).await.unwrap().unwrap();
-                    let mut total: Amount = Amount::ZERO;
-                    let mut remain: Option<AgeAmount> = None;
-                    amounts.amounts.retain(|_amount| {
-                        if total.ge(&amount) {
-                            return true;
-                        }
-                        total = total.saturating_add(_amount.amount);
-                        if total.ge(&amount) {
-                            match total.try_sub(amount) {
-                                Ok(result) => {
-                                    remain = Some(AgeAmount {
-                                        amount: result,
-                                        expired: Timestamp::from(
-                                            now.micros()
-                                                .saturating_add(*self.amount_alive_ms.get()),
-                                        ),
-                                    })
-                                }
-                                _ => {}
-                            }
-                            return false;
-                        }
-                        return false;
-                    });
-                    match remain {
-                        Some(result) => amounts.amounts.push(result),
-                        _ => {}
-                    }
-                    self.balances.insert(&from, amounts).unwrap();
-                    match self.balances.get(&to).await {
-                        Ok(Some(mut amounts)) => {
-                            amounts.amounts.push(AgeAmount {
-                                amount,
-                                expired: Timestamp::from(
-                                    now.micros().saturating_add(*self.amount_alive_ms.get()),
-                                ),
-                            });
-                            self.balances.insert(&to, amounts).unwrap();
-                        }
-                        _ => self
-                            .balances
-                            .insert(
-                                &to,
-                                AgeAmounts {
-                                    amounts: vec![AgeAmount {
-                                        amount,
-                                        expired: Timestamp::from(
-                                            now.micros()
-                                                .saturating_add(*self.amount_alive_ms.get()),
-                                        ),
-                                    }],
-                                },
-                            )
-                            .unwrap(),
-                    }
-                    match self.spendables.get(&to).await {
-                        Ok(Some(spendable)) => self
-                            .spendables
-                            .insert(&to, spendable.saturating_add(amount))?,
-                        _ => self.spendables.insert(&to, amount)?,
-                    }
-                    Ok(())
-                }
+        check_memo(&memo)?;
+        self.ensure_not_importing()?;
+        self.ensure_not_frozen(from).await?;
+        self.ensure_not_frozen(to).await?;
+
+        let spendable = self.spendables.get(&from).await?.unwrap_or_default();
+        let insufficient = StateError::InsufficientAccountBalance {
+            owner: from,
+            required: amount,
+            available: spendable,
+        };
+        if spendable < amount {
+            return Err(insufficient);
+        }
+        let Some(mut amounts) = self.balances.get(&from).await? else {
+            return Err(insufficient);
+        };
+
+        self.spendables
+            .insert(&from, spendable.saturating_sub(amount))?;
+        // Lots move with their expiry, so transfers neither extend nor
+        // shorten the lifetime of the credit
+        let taken = amounts.take(amount);
+        self.balances.insert(&from, amounts)?;
+
+        let mut amounts = self.balances.get(&to).await?.unwrap_or(AgeAmounts {
+            amounts: Vec::new(),
+        });
+        amounts.amounts.extend(taken);
+        self.balances.insert(&to, amounts)?;
+
+        let spendable = self.spendables.get(&to).await?.unwrap_or_default();
+        self.spendables
+            .insert(&to, spendable.saturating_add(amount))?;
+
+        self.record_transaction(Transaction {
+            kind: TransactionKind::Transfer,
+            from: Some(from),
+            to,
+            amount,
+            memo,
+            reference,
+            timestamp: now,
+        })
+        .await
+    }
+
+    async fn batch_reward(
+        &mut self,
+        entries: Vec<RewardEntry>,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, StateError> {
+        for entry in &entries {
+            self.reward(entry.owner, entry.amount, now, None, None, None)
+                .await?;
+        }
+        let mut results = Vec::new();
+        for entry in entries {
+            results.push(BatchEntryResult {
+                owner: entry.owner,
+                amount: entry.amount,
+                balance: self.balance_at(entry.owner, now).await?,
+            });
+        }
+        Ok(results)
+    }
+
+    async fn batch_transfer(
+        &mut self,
+        from: AccountOwner,
+        entries: Vec<TransferEntry>,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, StateError> {
+        for entry in &entries {
+            self.transfer(from, entry.to, entry.amount, now, None, None)
+                .await?;
+        }
+        let mut results = Vec::new();
+        for entry in entries {
+            results.push(BatchEntryResult {
+                owner: entry.to,
+                amount: entry.amount,
+                balance: self.balance_at(entry.to, now).await?,
+            });
+        }
+        Ok(results)
+    }
+
+    async fn hold(
+        &mut self,
+        hold_id: String,
+        owner: AccountOwner,
+        operator: AccountOwner,
+        amount: Amount,
+        expires_at: Timestamp,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        self.place_hold(hold_id, owner, operator, amount, expires_at, now)
+            .await
+    }
+
+    async fn capture_hold(
+        &mut self,
+        hold_id: String,
+        operator: AccountOwner,
+        to: AccountOwner,
+    ) -> Result<Hold, StateError> {
+        // Releasing stays allowed, the credit goes back to a frozen owner
+        if let Some(hold) = self.holds.get(&hold_id).await? {
+            self.ensure_not_frozen(hold.owner).await?;
+        }
+        self.ensure_not_frozen(to).await?;
+        self.settle_hold(&hold_id, Some(operator), Some(to)).await
+    }
+
+    async fn release_hold(
+        &mut self,
+        hold_id: String,
+        operator: AccountOwner,
+    ) -> Result<Hold, StateError> {
+        self.settle_hold(&hold_id, Some(operator), None).await
+    }
+
+    fn create_schedule(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        interval_ms: u64,
+        count: u32,
+        start_at: Timestamp,
+    ) -> Result<u64, StateError> {
+        self.add_schedule(owner, amount, interval_ms, count, start_at)
+    }
+
+    async fn cancel_schedule(&mut self, schedule_id: u64) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        if !self.schedules.contains_key(&schedule_id).await? {
+            return Err(StateError::ScheduleNotFound(schedule_id));
+        }
+        self.schedules.remove(&schedule_id)?;
+        Ok(())
+    }
+
+    async fn process_schedules(
+        &mut self,
+        max: u32,
+        now: Timestamp,
+    ) -> Result<Vec<BatchEntryResult>, StateError> {
+        self.issue_due_schedules(max, now).await
+    }
+
+    async fn burn(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        self.ensure_not_frozen(owner).await?;
+        // Expired lots go back to the supply balance instead of being burnt
+        self.liquidate_owner(owner, now).await?;
+        let spendable = self.spendables.get(&owner).await?.unwrap_or_default();
+        let insufficient = StateError::InsufficientAccountBalance {
+            owner,
+            required: amount,
+            available: spendable,
+        };
+        if spendable < amount {
+            return Err(insufficient);
+        }
+        let Some(mut amounts) = self.balances.get(&owner).await? else {
+            return Err(insufficient);
+        };
+        self.spendables
+            .insert(&owner, spendable.saturating_sub(amount))?;
+
+        amounts.take(amount);
+        self.balances.insert(&owner, amounts)?;
+
+        let initial_supply = self._initial_supply.get().saturating_sub(amount);
+        self._initial_supply.set(initial_supply);
+        self.supply_history.push(SupplyChange {
+            kind: SupplyChangeKind::Burn,
+            owner,
+            amount,
+            initial_supply,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    fn mint(
+        &mut self,
+        owner: AccountOwner,
+        amount: Amount,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        let initial_supply = self._initial_supply.get().saturating_add(amount);
+        self._initial_supply.set(initial_supply);
+        self._balance
+            .set(self._balance.get().saturating_add(amount));
+        self.supply_history.push(SupplyChange {
+            kind: SupplyChangeKind::Mint,
+            owner,
+            amount,
+            initial_supply,
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    fn update_config(
+        &mut self,
+        amount_alive_ms: u64,
+        max_ttl_ms: u64,
+        changed_by: AccountOwner,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        if amount_alive_ms == 0 || (max_ttl_ms > 0 && amount_alive_ms > max_ttl_ms) {
+            return Err(StateError::InvalidTtl {
+                ttl_ms: amount_alive_ms,
+                max_ttl_ms,
+            });
+        }
+        self.config_changes.push(ConfigChange {
+            changed_by,
+            previous_amount_alive_ms: *self.amount_alive_ms.get(),
+            amount_alive_ms,
+            timestamp: now,
+        });
+        self.amount_alive_ms.set(amount_alive_ms);
+        Ok(())
+    }
+
+    fn freeze(
+        &mut self,
+        owner: AccountOwner,
+        allow_rewards: bool,
+        frozen_by: AccountOwner,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        self.frozen.insert(
+            &owner,
+            Freeze {
+                frozen_by,
+                allow_rewards,
+                timestamp: now,
             },
-            _ => return Err(StateError::InsufficientAccountBalance),
+        )?;
+        Ok(())
+    }
+
+    async fn unfreeze(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        self.ensure_not_importing()?;
+        if !self.frozen.contains_key(&owner).await? {
+            return Err(StateError::NotFrozen(owner));
+        }
+        self.frozen.remove(&owner)?;
+        Ok(())
+    }
+
+    async fn import_state(&mut self, records: Vec<StateRecord>) -> Result<(), StateError> {
+        self.apply_state_records(records, STATE_VERSION - 1).await
+    }
+
+    fn abort_import(&mut self) -> Result<(), StateError> {
+        CreditState::abort_import(self)
+    }
+
+    async fn import_snapshot(
+        &mut self,
+        records: Vec<StateRecord>,
+        hash: String,
+    ) -> Result<(), StateError> {
+        let unchained = records.iter().any(|record| {
+            matches!(
+                record,
+                StateRecord::Finalize(FinalizeRecord {
+                    state_hash: None,
+                    ..
+                })
+            )
+        });
+        if unchained {
+            return Err(StateError::MissingStateHash);
+        }
+        let actual = records_hash(&records)?;
+        if actual != hash {
+            return Err(StateError::SnapshotHashMismatch {
+                expected: hash,
+                actual,
+            });
+        }
+        self.apply_state_records(records, STATE_VERSION).await
+    }
+}
+
+impl CreditState {
+    /// Sum of the lots of `owner` which are not yet expired at `now`
+    pub(crate) async fn balance_at(
+        &self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<Amount, StateError> {
+        Ok(self
+            .balances
+            .get(&owner)
+            .await?
+            .map(|amounts| amounts.alive_sum(now))
+            .unwrap_or_default())
+    }
+
+    /// Returns the expired lots of `owner` to the supply balance
+    pub(crate) async fn liquidate_owner(
+        &mut self,
+        owner: AccountOwner,
+        now: Timestamp,
+    ) -> Result<(), StateError> {
+        let Some(mut amounts) = self.balances.get(&owner).await? else {
+            return Ok(());
+        };
+        let Some(mut spendable) = self.spendables.get(&owner).await? else {
+            return Ok(());
+        };
+        amounts.amounts.retain(|amount| {
+            let expired = amount.is_expired(now);
+            if expired {
+                self._balance
+                    .set(self._balance.get().saturating_add(amount.amount));
+                spendable = spendable.saturating_sub(amount.amount);
+            }
+            !expired
+        });
+        self.spendables.insert(&owner, spendable)?;
+        self.balances.insert(&owner, amounts)?;
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl RoleStore for CreditState {
+    async fn roles(&self, owner: AccountOwner) -> Result<Vec<Role>, ViewError> {
+        Ok(self.roles.get(&owner).await?.unwrap_or_default())
+    }
+
+    fn set_roles(&mut self, owner: AccountOwner, roles: Vec<Role>) -> Result<(), ViewError> {
+        match roles.is_empty() {
+            true => self.roles.remove(&owner),
+            false => self.roles.insert(&owner, roles),
         }
     }
+
+    async fn holders(&self, role: Role) -> Result<Vec<AccountOwner>, ViewError> {
+        let mut holders = Vec::new();
+        self.roles
+            .for_each_index_value(|owner, roles| {
+                if roles.contains(&role) {
+                    holders.push(owner);
+                }
+                Ok(())
+            })
+            .await?;
+        Ok(holders)
+    }
 }
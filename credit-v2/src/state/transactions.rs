@@ -0,0 +1,29 @@
+use super::{errors::StateError, CreditState};
+use crate::types::{Transaction, MAX_MEMO_LENGTH};
+
+/// Rejects the memos longer than `MAX_MEMO_LENGTH`
+pub fn check_memo(memo: &Option<String>) -> Result<(), StateError> {
+    match memo {
+        Some(memo) if memo.len() > MAX_MEMO_LENGTH => Err(StateError::MemoTooLong {
+            length: memo.len(),
+            max: MAX_MEMO_LENGTH,
+        }),
+        _ => Ok(()),
+    }
+}
+
+impl CreditState {
+    /// Appends `transaction` to the history and indexes its reference
+    pub(crate) async fn record_transaction(
+        &mut self,
+        transaction: Transaction,
+    ) -> Result<(), StateError> {
+        if let Some(reference) = transaction.reference {
+            let mut positions = self.references.get(&reference).await?.unwrap_or_default();
+            positions.push(self.transactions.count() as u32);
+            self.references.insert(&reference, positions)?;
+        }
+        self.transactions.push(transaction);
+        Ok(())
+    }
+}
@@ -1,8 +1,12 @@
-use async_graphql::SimpleObject;
-use linera_sdk::linera_base_types::{Amount, Timestamp};
+use async_graphql::{Enum, InputObject, OneofObject, SimpleObject};
+use base::types::Role;
+use linera_sdk::linera_base_types::{
+    AccountOwner, Amount, ApplicationId, CryptoHash, TimeDelta, Timestamp,
+};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Clone, SimpleObject)]
+#[derive(Debug, Deserialize, Serialize, Clone, SimpleObject, InputObject)]
+#[graphql(input_name = "AgeAmountInput")]
 pub struct AgeAmount {
     pub amount: Amount,
     pub expired: Timestamp,
@@ -13,12 +17,308 @@ pub struct AgeAmounts {
     pub amounts: Vec<AgeAmount>,
 }
 
+/// Expiry of a rewarded lot, the application's lot lifetime is used when it's not given
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, OneofObject, Eq, PartialEq)]
+pub enum RewardExpiry {
+    At(Timestamp),
+    TtlMs(u64),
+    /// The lot never expires. A oneof input needs a value, it must be `true`
+    Never(bool),
+}
+
+impl RewardExpiry {
+    pub fn expired(&self, now: Timestamp) -> Timestamp {
+        match *self {
+            RewardExpiry::At(expired) => expired,
+            RewardExpiry::TtlMs(ttl_ms) => AgeAmount::expired_after(now, ttl_ms),
+            RewardExpiry::Never(_) => AgeAmount::never_expired(),
+        }
+    }
+}
+
+impl AgeAmount {
+    /// Marker stored in `expired` by the lots which never expire
+    pub fn never_expired() -> Timestamp {
+        Timestamp::from(u64::MAX)
+    }
+
+    /// Expiry of a lot issued at `now` which lives `ttl_ms` milliseconds. Lots issued
+    /// before the lifetime was counted in milliseconds keep their stored expiry, see the
+    /// migration note in `documentation/CHANGELOG.md`
+    pub fn expired_after(now: Timestamp, ttl_ms: u64) -> Timestamp {
+        now.saturating_add(TimeDelta::from_millis(ttl_ms))
+    }
+
+    pub fn never_expires(&self) -> bool {
+        self.expired == Self::never_expired()
+    }
+
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        !self.never_expires() && now.micros() > self.expired.micros()
+    }
+}
+
 impl AgeAmounts {
     pub fn sum(&self) -> Amount {
-        let mut _sum = Amount::ZERO;
         self.amounts
             .iter()
-            .for_each(|a| _sum = _sum.try_add(a.amount).unwrap());
-        _sum
+            .fold(Amount::ZERO, |sum, a| sum.saturating_add(a.amount))
+    }
+
+    /// Takes `amount` out of the lots which expire first, splitting the last lot taken.
+    ///
+    /// The taken lots keep their expiry. The caller checks the lots hold enough.
+    pub fn take(&mut self, amount: Amount) -> Vec<AgeAmount> {
+        self.amounts.sort_by_key(|a| a.expired);
+
+        let mut remain = amount;
+        let mut taken = Vec::new();
+        let mut kept = Vec::new();
+        for lot in self.amounts.drain(..) {
+            if remain == Amount::ZERO {
+                kept.push(lot);
+            } else if lot.amount <= remain {
+                remain = remain.saturating_sub(lot.amount);
+                taken.push(lot);
+            } else {
+                taken.push(AgeAmount {
+                    amount: remain,
+                    expired: lot.expired,
+                });
+                kept.push(AgeAmount {
+                    amount: lot.amount.saturating_sub(remain),
+                    expired: lot.expired,
+                });
+                remain = Amount::ZERO;
+            }
+        }
+        self.amounts = kept;
+        taken
+    }
+
+    /// Sum of the amounts which are not expired at `now`
+    pub fn alive_sum(&self, now: Timestamp) -> Amount {
+        self.amounts
+            .iter()
+            .filter(|a| !a.is_expired(now))
+            .fold(Amount::ZERO, |sum, a| sum.saturating_add(a.amount))
     }
 }
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Enum, Eq, PartialEq)]
+pub enum Invariant {
+    /// Initial supply equals the supply balance plus all spendables and holds
+    SupplyConservation,
+    /// Spendable of an owner equals the sum of its balances
+    SpendableBalance,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject)]
+pub struct InvariantViolation {
+    pub invariant: Invariant,
+    pub owners: Vec<AccountOwner>,
+    pub expected: Amount,
+    pub actual: Amount,
+}
+
+/// Layout version of the credit state, bumped when the views change
+pub const STATE_VERSION: u32 = 3;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, SimpleObject)]
+pub struct StateHeader {
+    pub version: u32,
+    /// Set by an imported header record and cleared once the import is finalized
+    pub importing: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, InputObject)]
+pub struct HeaderRecord {
+    /// State version of the exporting application
+    pub version: u32,
+    pub initial_supply: Amount,
+    pub supply_balance: Amount,
+    pub amount_alive_ms: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, InputObject)]
+pub struct AccountRecord {
+    pub owner: AccountOwner,
+    pub amounts: Vec<AgeAmount>,
+    pub spendable: Amount,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, InputObject)]
+pub struct FinalizeRecord {
+    pub accounts: u32,
+    pub total_spendable: Amount,
+    /// Hash chained over every record before this one, see `snapshot::chain_hash`.
+    /// Required by `ImportSnapshot`, exports of older versions may leave it out.
+    pub state_hash: Option<String>,
+}
+
+/// Piece of an exported credit state, imported in order
+#[derive(Clone, Debug, Deserialize, Serialize, OneofObject)]
+pub enum StateRecord {
+    Header(HeaderRecord),
+    Account(AccountRecord),
+    RewardCallers(Vec<ApplicationId>),
+    TransferCallers(Vec<ApplicationId>),
+    Hold(HoldRecord),
+    Finalize(FinalizeRecord),
+    Freeze(FreezeRecord),
+    Schedule(ScheduleRecord),
+    NextScheduleId(u64),
+    Roles(RolesRecord),
+    SupplyChange(SupplyChange),
+    ConfigChange(ConfigChange),
+    Transaction(Transaction),
+}
+
+/// One page of a deterministic state snapshot
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject)]
+pub struct SnapshotPage {
+    /// Records of the page as JSON
+    pub records: String,
+    /// Records of the page as hex encoded BCS
+    pub bcs: String,
+    /// Hex encoded SHA3-256 of the BCS records, checked again by `ImportSnapshot`
+    pub hash: String,
+    /// Offset of the next page, none after the last page
+    pub next_offset: Option<u32>,
+}
+
+/// Change of the mutable settings, only lots issued afterwards use the new values
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "ConfigChangeInput")]
+pub struct ConfigChange {
+    pub changed_by: AccountOwner,
+    pub previous_amount_alive_ms: u64,
+    pub amount_alive_ms: u64,
+    pub timestamp: Timestamp,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Enum, Eq, PartialEq)]
+pub enum SupplyChangeKind {
+    Mint,
+    Burn,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "SupplyChangeInput")]
+pub struct SupplyChange {
+    pub kind: SupplyChangeKind,
+    /// Admin who minted or holder who burned
+    pub owner: AccountOwner,
+    pub amount: Amount,
+    /// Initial supply after the change
+    pub initial_supply: Amount,
+    pub timestamp: Timestamp,
+}
+
+/// Largest number of entries accepted by a batch operation
+pub const MAX_BATCH_SIZE: usize = 100;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, InputObject, Eq, PartialEq)]
+pub struct RewardEntry {
+    pub owner: AccountOwner,
+    pub amount: Amount,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, InputObject, Eq, PartialEq)]
+pub struct TransferEntry {
+    pub to: AccountOwner,
+    pub amount: Amount,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject)]
+pub struct BatchEntryResult {
+    pub owner: AccountOwner,
+    pub amount: Amount,
+    /// Balance of `owner` once the whole batch is applied
+    pub balance: Amount,
+}
+
+/// Credit locked for a pending purchase, it can't be spent until it's captured or released
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "HoldInput")]
+pub struct Hold {
+    pub owner: AccountOwner,
+    /// Application or account which placed the hold and may capture or release it
+    pub operator: AccountOwner,
+    pub amount: Amount,
+    /// Lots taken out of the holder's balances, they keep their expiry
+    pub lots: Vec<AgeAmount>,
+    /// The hold is released back to the holder by the first liquidation after it
+    pub expires_at: Timestamp,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, InputObject)]
+pub struct HoldRecord {
+    pub hold_id: String,
+    pub hold: Hold,
+}
+
+/// Recurring reward issued by `ProcessSchedules`
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "ScheduleInput")]
+pub struct Schedule {
+    pub owner: AccountOwner,
+    pub amount: Amount,
+    pub interval_ms: u64,
+    /// Installments which are not issued yet
+    pub remaining: u32,
+    /// Time from which the next installment is due
+    pub next_at: Timestamp,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, InputObject)]
+pub struct ScheduleRecord {
+    pub schedule_id: u64,
+    pub schedule: Schedule,
+}
+
+/// Longest memo accepted by rewards and transfers, in bytes
+pub const MAX_MEMO_LENGTH: usize = 256;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Enum, Eq, PartialEq)]
+pub enum TransactionKind {
+    Reward,
+    Transfer,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "TransactionInput")]
+pub struct Transaction {
+    pub kind: TransactionKind,
+    /// None for rewards, which are drawn from the supply balance
+    pub from: Option<AccountOwner>,
+    pub to: AccountOwner,
+    pub amount: Amount,
+    pub memo: Option<String>,
+    /// External reference, e.g. the hash of an off-chain order
+    pub reference: Option<CryptoHash>,
+    pub timestamp: Timestamp,
+}
+
+/// Freeze placed by an admin on an account, e.g. while investigating fraud
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "FreezeInput")]
+pub struct Freeze {
+    pub frozen_by: AccountOwner,
+    /// Whether the account keeps receiving rewards while frozen
+    pub allow_rewards: bool,
+    pub timestamp: Timestamp,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject, InputObject)]
+#[graphql(input_name = "FreezeRecordInput")]
+pub struct FreezeRecord {
+    pub owner: AccountOwner,
+    pub freeze: Freeze,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, InputObject)]
+pub struct RolesRecord {
+    pub owner: AccountOwner,
+    pub roles: Vec<Role>,
+}
@@ -0,0 +1,100 @@
+//! Balances read by the contract and by the service against an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{new_state, owner};
+use credit_v2::{
+    interfaces::state::{StateInterface, StateQueryInterface},
+    state::errors::StateError,
+    types::RewardExpiry,
+};
+use linera_sdk::linera_base_types::{Amount, TimeDelta, Timestamp};
+
+const AMOUNT_ALIVE_MS: u64 = 1000;
+
+#[tokio::test]
+async fn expired_lots_are_not_counted_before_liquidation() {
+    let alice = owner("alice");
+    let rewarded_at = Timestamp::from(0);
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    state
+        .reward(alice, Amount::from_tokens(3), rewarded_at, None, None, None)
+        .await
+        .expect("Failed to reward");
+    let later = rewarded_at.saturating_add(TimeDelta::from_millis(AMOUNT_ALIVE_MS / 2));
+    state
+        .reward(alice, Amount::from_tokens(4), later, None, None, None)
+        .await
+        .expect("Failed to reward");
+
+    let now = rewarded_at.saturating_add(TimeDelta::from_millis(AMOUNT_ALIVE_MS + 1));
+    let alive = state
+        .alive_balance(alice, now)
+        .await
+        .expect("Failed to read balance");
+    assert_eq!(alive, Amount::from_tokens(4));
+    assert_eq!(
+        StateQueryInterface::balance(&state, alice, now)
+            .await
+            .expect("Failed to read balance"),
+        alive
+    );
+}
+
+#[tokio::test]
+async fn reward_expiries_set_the_lot_lifetime() {
+    let alice = owner("alice");
+    let now = Timestamp::from(0);
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    // Milliseconds, not microseconds
+    state
+        .reward(
+            alice,
+            Amount::ONE,
+            now,
+            Some(RewardExpiry::TtlMs(2)),
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to reward");
+    state
+        .reward(
+            alice,
+            Amount::ONE,
+            now,
+            Some(RewardExpiry::Never(true)),
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to reward");
+    assert!(matches!(
+        state
+            .reward(
+                alice,
+                Amount::ONE,
+                now,
+                Some(RewardExpiry::Never(false)),
+                None,
+                None,
+            )
+            .await,
+        Err(StateError::InvalidNeverExpiry)
+    ));
+
+    for (micros, expected) in [(1_999, Amount::from_tokens(2)), (2_001, Amount::ONE)] {
+        let at = now.saturating_add(TimeDelta::from_micros(micros));
+        assert_eq!(
+            state
+                .alive_balance(alice, at)
+                .await
+                .expect("Failed to read balance"),
+            expected
+        );
+    }
+}
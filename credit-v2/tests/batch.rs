@@ -0,0 +1,192 @@
+//! Batch rewards and transfers against an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use std::{cell::RefCell, rc::Rc};
+
+use base::{
+    handler::errors::HandlerError, interfaces::unit_of_work::UnitOfWork,
+    runtime::unit_of_work::ViewUnitOfWork,
+};
+use common::{new_state, owner};
+use credit_v2::{
+    contract_inner::batch::check_batch_size,
+    interfaces::state::StateInterface,
+    state::{adapter::StateAdapter, errors::StateError},
+    types::{RewardEntry, TransferEntry, MAX_BATCH_SIZE},
+};
+use linera_sdk::linera_base_types::{Amount, Timestamp};
+
+#[tokio::test]
+async fn batch_results_carry_the_final_balances() {
+    let (alice, bob, carol) = (owner("alice"), owner("bob"), owner("carol"));
+    let now = Timestamp::from(0);
+    let mut state = new_state(1_000_000).await;
+
+    let results = state
+        .batch_reward(
+            vec![
+                RewardEntry {
+                    owner: alice,
+                    amount: Amount::from_tokens(10),
+                },
+                RewardEntry {
+                    owner: bob,
+                    amount: Amount::from_tokens(2),
+                },
+                RewardEntry {
+                    owner: alice,
+                    amount: Amount::from_tokens(5),
+                },
+            ],
+            now,
+        )
+        .await
+        .expect("Failed to batch reward");
+    assert_eq!(
+        results
+            .iter()
+            .map(|result| (result.owner, result.amount, result.balance))
+            .collect::<Vec<_>>(),
+        vec![
+            (alice, Amount::from_tokens(10), Amount::from_tokens(15)),
+            (bob, Amount::from_tokens(2), Amount::from_tokens(2)),
+            (alice, Amount::from_tokens(5), Amount::from_tokens(15)),
+        ]
+    );
+
+    let results = state
+        .batch_transfer(
+            alice,
+            vec![
+                TransferEntry {
+                    to: bob,
+                    amount: Amount::from_tokens(4),
+                },
+                TransferEntry {
+                    to: carol,
+                    amount: Amount::from_tokens(6),
+                },
+            ],
+            now,
+        )
+        .await
+        .expect("Failed to batch transfer");
+    assert_eq!(
+        results
+            .iter()
+            .map(|result| (result.owner, result.amount, result.balance))
+            .collect::<Vec<_>>(),
+        vec![
+            (bob, Amount::from_tokens(4), Amount::from_tokens(6)),
+            (carol, Amount::from_tokens(6), Amount::from_tokens(6)),
+        ]
+    );
+    assert_eq!(
+        state
+            .alive_balance(alice, now)
+            .await
+            .expect("Failed to read balance"),
+        Amount::from_tokens(5)
+    );
+}
+
+#[test]
+fn batch_size_is_bounded() {
+    for size in [0, MAX_BATCH_SIZE + 1] {
+        let err = check_batch_size(size).expect_err("Invalid batch size accepted");
+        let HandlerError::State(layer) = &err else {
+            panic!("Expected a state error, got {err:?}");
+        };
+        assert!(matches!(
+            layer.decode::<StateError>(),
+            Some(StateError::InvalidBatchSize { size: invalid, max })
+                if invalid == size && max == MAX_BATCH_SIZE
+        ));
+    }
+    check_batch_size(1).expect("Single entry batch rejected");
+    check_batch_size(MAX_BATCH_SIZE).expect("Full batch rejected");
+}
+
+#[tokio::test]
+async fn failed_batch_transfer_is_rolled_back() {
+    let (alice, bob, carol, dave) = (owner("alice"), owner("bob"), owner("carol"), owner("dave"));
+    let now = Timestamp::from(0);
+    let state = Rc::new(RefCell::new(new_state(1_000_000).await));
+    let mut adapter = StateAdapter::new(state.clone());
+    adapter
+        .reward(alice, Amount::from_tokens(10), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+
+    // The third entry needs more than what the first two left
+    let mut unit_of_work = ViewUnitOfWork::new(state.clone());
+    unit_of_work.begin().expect("Failed to begin");
+    let entries = [(bob, 3), (carol, 3), (dave, 5)]
+        .into_iter()
+        .map(|(to, tokens)| TransferEntry {
+            to,
+            amount: Amount::from_tokens(tokens),
+        })
+        .collect();
+    assert!(matches!(
+        adapter.batch_transfer(alice, entries, now).await,
+        Err(StateError::InsufficientAccountBalance { owner, .. }) if owner == alice
+    ));
+    unit_of_work.rollback();
+
+    for (owner, expected) in [
+        (alice, Amount::from_tokens(10)),
+        (bob, Amount::ZERO),
+        (carol, Amount::ZERO),
+        (dave, Amount::ZERO),
+    ] {
+        assert_eq!(
+            adapter
+                .alive_balance(owner, now)
+                .await
+                .expect("Failed to read balance"),
+            expected
+        );
+    }
+}
+
+#[tokio::test]
+async fn failed_batch_reward_is_rolled_back() {
+    let (alice, bob, carol, admin) = (owner("alice"), owner("bob"), owner("carol"), owner("admin"));
+    let now = Timestamp::from(0);
+    let state = Rc::new(RefCell::new(new_state(1_000_000).await));
+    let mut adapter = StateAdapter::new(state.clone());
+    adapter
+        .freeze(carol, false, admin, now)
+        .expect("Failed to freeze");
+
+    // The third entry rewards a frozen account
+    let mut unit_of_work = ViewUnitOfWork::new(state.clone());
+    unit_of_work.begin().expect("Failed to begin");
+    let entries = [alice, bob, carol]
+        .into_iter()
+        .map(|owner| RewardEntry {
+            owner,
+            amount: Amount::from_tokens(10),
+        })
+        .collect();
+    assert!(matches!(
+        adapter.batch_reward(entries, now).await,
+        Err(StateError::FrozenAccount(owner)) if owner == carol
+    ));
+    unit_of_work.rollback();
+
+    assert_eq!(*state.borrow()._balance.get(), Amount::from_tokens(1000));
+    for owner in [alice, bob] {
+        assert_eq!(
+            adapter
+                .alive_balance(owner, now)
+                .await
+                .expect("Failed to read balance"),
+            Amount::ZERO
+        );
+    }
+}
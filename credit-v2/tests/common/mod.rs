@@ -0,0 +1,34 @@
+//! Fixtures shared by the tests running against an in-memory credit state.
+
+#![allow(dead_code)]
+
+use credit_v2::{
+    instantiation_argument::InstantiationArgument, interfaces::state::StateInterface,
+    state::CreditState,
+};
+use linera_sdk::{
+    linera_base_types::{AccountOwner, Amount, CryptoHash},
+    views::{KeyValueStore, View, ViewStorageContext},
+};
+
+pub fn owner(name: &str) -> AccountOwner {
+    AccountOwner::from(CryptoHash::test_hash(name))
+}
+
+/// State which is not instantiated yet, e.g. the target of an import
+pub async fn empty_state() -> CreditState {
+    let context = ViewStorageContext::new_unchecked(KeyValueStore::mock(), Vec::new(), ());
+    CreditState::load(context)
+        .await
+        .expect("Failed to load state")
+}
+
+/// State instantiated with a supply of 1000 tokens
+pub async fn new_state(amount_alive_ms: u64) -> CreditState {
+    let mut state = empty_state().await;
+    state.instantiate(InstantiationArgument {
+        initial_supply: Amount::from_tokens(1000),
+        amount_alive_ms,
+    });
+    state
+}
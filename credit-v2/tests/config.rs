@@ -0,0 +1,127 @@
+//! Updates of the lot lifetime against an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{new_state, owner};
+use credit_v2::{
+    interfaces::state::{StateInterface, StateQueryInterface},
+    state::errors::StateError,
+    types::AgeAmount,
+};
+use linera_sdk::linera_base_types::{Amount, TimeDelta, Timestamp};
+
+const AMOUNT_ALIVE_MS: u64 = 1000;
+const MAX_TTL_MS: u64 = 10_000;
+
+#[tokio::test]
+async fn update_config_only_applies_to_later_lots() {
+    let (admin, alice) = (owner("admin"), owner("alice"));
+    let issued_at = Timestamp::from(0);
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    state
+        .reward(alice, Amount::from_tokens(1), issued_at, None, None, None)
+        .await
+        .expect("Failed to reward");
+    state
+        .update_config(5 * AMOUNT_ALIVE_MS, MAX_TTL_MS, admin, issued_at)
+        .expect("Failed to update config");
+    state
+        .reward(alice, Amount::from_tokens(2), issued_at, None, None, None)
+        .await
+        .expect("Failed to reward");
+
+    let lots = state
+        .alive_amounts(alice, issued_at)
+        .await
+        .expect("Failed to read lots");
+    assert_eq!(
+        lots.iter().map(|lot| lot.expired).collect::<Vec<_>>(),
+        vec![
+            AgeAmount::expired_after(issued_at, AMOUNT_ALIVE_MS),
+            AgeAmount::expired_after(issued_at, 5 * AMOUNT_ALIVE_MS),
+        ]
+    );
+
+    // Past the old lifetime only the lot issued after the update is alive
+    let later = issued_at.saturating_add(TimeDelta::from_millis(2 * AMOUNT_ALIVE_MS));
+    assert_eq!(
+        StateInterface::alive_balance(&state, alice, later)
+            .await
+            .expect("Failed to read balance"),
+        Amount::from_tokens(2)
+    );
+}
+
+#[tokio::test]
+async fn update_config_is_logged() {
+    let admin = owner("admin");
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    state
+        .update_config(2 * AMOUNT_ALIVE_MS, MAX_TTL_MS, admin, Timestamp::from(7))
+        .expect("Failed to update config");
+    state
+        .update_config(3 * AMOUNT_ALIVE_MS, MAX_TTL_MS, admin, Timestamp::from(9))
+        .expect("Failed to update config");
+
+    let changes = state
+        .config_changes
+        .read(..)
+        .await
+        .expect("Failed to read config changes");
+    assert_eq!(
+        changes
+            .iter()
+            .map(|change| (
+                change.changed_by,
+                change.previous_amount_alive_ms,
+                change.amount_alive_ms,
+                change.timestamp
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                admin,
+                AMOUNT_ALIVE_MS,
+                2 * AMOUNT_ALIVE_MS,
+                Timestamp::from(7)
+            ),
+            (
+                admin,
+                2 * AMOUNT_ALIVE_MS,
+                3 * AMOUNT_ALIVE_MS,
+                Timestamp::from(9)
+            ),
+        ]
+    );
+    assert_eq!(*state.amount_alive_ms.get(), 3 * AMOUNT_ALIVE_MS);
+}
+
+#[tokio::test]
+async fn update_config_rejects_zero_and_above_max() {
+    let admin = owner("admin");
+    let now = Timestamp::from(0);
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    for ttl_ms in [0, MAX_TTL_MS + 1] {
+        assert!(matches!(
+            state.update_config(ttl_ms, MAX_TTL_MS, admin, now),
+            Err(StateError::InvalidTtl { ttl_ms: rejected, max_ttl_ms: MAX_TTL_MS })
+                if rejected == ttl_ms
+        ));
+    }
+    // Zero bound leaves the lifetime unbounded, but still not zero
+    state
+        .update_config(100 * MAX_TTL_MS, 0, admin, now)
+        .expect("Failed to update config");
+    assert!(matches!(
+        state.update_config(0, 0, admin, now),
+        Err(StateError::InvalidTtl { ttl_ms: 0, .. })
+    ));
+
+    assert_eq!(state.config_changes.count(), 1);
+    assert_eq!(*state.amount_alive_ms.get(), 100 * MAX_TTL_MS);
+}
@@ -0,0 +1,32 @@
+//! State errors reported through operation responses keep their context.
+
+use base::handler::errors::HandlerError;
+use credit_v2::state::errors::StateError;
+use linera_sdk::linera_base_types::{AccountOwner, Amount, CryptoHash};
+
+#[test]
+fn insufficient_balance_keeps_required_and_available() {
+    let owner = AccountOwner::from(CryptoHash::test_hash("owner"));
+    let err = HandlerError::state(StateError::InsufficientAccountBalance {
+        owner,
+        required: Amount::from_tokens(5),
+        available: Amount::ONE,
+    });
+
+    let HandlerError::State(layer) = &err else {
+        panic!("Expected a state error, got {err:?}");
+    };
+    assert_eq!(layer.code, "InsufficientAccountBalance");
+    match layer.decode::<StateError>() {
+        Some(StateError::InsufficientAccountBalance {
+            owner: decoded,
+            required,
+            available,
+        }) => {
+            assert_eq!(decoded, owner);
+            assert_eq!(required, Amount::from_tokens(5));
+            assert_eq!(available, Amount::ONE);
+        }
+        other => panic!("Unexpected decoded error {other:?}"),
+    }
+}
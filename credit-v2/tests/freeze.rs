@@ -0,0 +1,133 @@
+//! Freezing of accounts against an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{new_state, owner};
+use credit_v2::{
+    interfaces::state::{StateInterface, StateQueryInterface},
+    state::errors::StateError,
+};
+use linera_sdk::linera_base_types::{Amount, TimeDelta, Timestamp};
+
+#[tokio::test]
+async fn frozen_account_cannot_send_or_receive() {
+    let (alice, bob, admin) = (owner("alice"), owner("bob"), owner("admin"));
+    let now = Timestamp::from(0);
+    let mut state = new_state(1_000_000).await;
+
+    state
+        .reward(alice, Amount::from_tokens(10), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+    state
+        .reward(bob, Amount::from_tokens(10), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+    state
+        .freeze(alice, false, admin, now)
+        .expect("Failed to freeze");
+
+    let sent = state
+        .transfer(alice, bob, Amount::ONE, now, None, None)
+        .await;
+    assert!(matches!(sent, Err(StateError::FrozenAccount(owner)) if owner == alice));
+    let received = state
+        .transfer(bob, alice, Amount::ONE, now, None, None)
+        .await;
+    assert!(matches!(received, Err(StateError::FrozenAccount(owner)) if owner == alice));
+    let rewarded = state
+        .reward(alice, Amount::ONE, now, None, None, None)
+        .await;
+    assert!(matches!(rewarded, Err(StateError::FrozenAccount(_))));
+
+    let frozen = state
+        .frozen_accounts(0, 10)
+        .await
+        .expect("Failed to list frozen accounts");
+    assert_eq!(frozen.len(), 1);
+    assert_eq!(frozen[0].owner, alice);
+    assert_eq!(frozen[0].freeze.frozen_by, admin);
+
+    state.unfreeze(alice).await.expect("Failed to unfreeze");
+    state
+        .transfer(alice, bob, Amount::ONE, now, None, None)
+        .await
+        .expect("Failed to transfer after unfreeze");
+    assert!(matches!(
+        state.unfreeze(alice).await,
+        Err(StateError::NotFrozen(_))
+    ));
+}
+
+#[tokio::test]
+async fn freeze_may_allow_rewards() {
+    let (alice, admin) = (owner("alice"), owner("admin"));
+    let now = Timestamp::from(0);
+    let mut state = new_state(1_000_000).await;
+
+    state
+        .freeze(alice, true, admin, now)
+        .expect("Failed to freeze");
+    state
+        .reward(alice, Amount::ONE, now, None, None, None)
+        .await
+        .expect("Rewards are allowed while frozen");
+
+    assert_eq!(
+        state
+            .alive_balance(alice, now)
+            .await
+            .expect("Failed to read balance"),
+        Amount::ONE
+    );
+}
+
+#[tokio::test]
+async fn frozen_account_cannot_capture_or_burn() {
+    let (alice, bob, operator, admin) = (
+        owner("alice"),
+        owner("bob"),
+        owner("operator"),
+        owner("admin"),
+    );
+    let now = Timestamp::from(0);
+    let expires_at = now.saturating_add(TimeDelta::from_millis(1000));
+    let mut state = new_state(1_000_000).await;
+
+    state
+        .reward(alice, Amount::from_tokens(10), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+    state
+        .hold(
+            "order".to_string(),
+            alice,
+            operator,
+            Amount::from_tokens(6),
+            expires_at,
+            now,
+        )
+        .await
+        .expect("Failed to hold");
+    state
+        .freeze(alice, false, admin, now)
+        .expect("Failed to freeze");
+
+    let captured = state.capture_hold("order".to_string(), operator, bob).await;
+    assert!(matches!(captured, Err(StateError::FrozenAccount(owner)) if owner == alice));
+    let burnt = state.burn(alice, Amount::ONE, now).await;
+    assert!(matches!(burnt, Err(StateError::FrozenAccount(owner)) if owner == alice));
+
+    // Releasing only gives the held credit back to the frozen owner
+    state
+        .release_hold("order".to_string(), operator)
+        .await
+        .expect("Failed to release hold");
+    assert_eq!(
+        state.spendables.get(&alice).await.unwrap(),
+        Some(Amount::from_tokens(10))
+    );
+    assert_eq!(state.spendables.get(&bob).await.unwrap(), None);
+}
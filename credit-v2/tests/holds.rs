@@ -0,0 +1,156 @@
+//! Holds placed, settled and released by the liquidation against an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{new_state, owner};
+use credit_v2::{
+    interfaces::state::StateInterface,
+    state::{errors::StateError, CreditState},
+};
+use linera_sdk::linera_base_types::{Amount, TimeDelta, Timestamp};
+
+async fn assert_invariants(state: &CreditState) {
+    assert!(state
+        .check_invariants()
+        .await
+        .expect("Failed to check invariants")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn held_credit_cannot_be_spent_twice() {
+    let (alice, bob, operator) = (owner("alice"), owner("bob"), owner("operator"));
+    let now = Timestamp::from(0);
+    let expires_at = now.saturating_add(TimeDelta::from_millis(1000));
+    let mut state = new_state(1_000_000).await;
+    state
+        .reward(alice, Amount::from_tokens(10), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+
+    state
+        .hold(
+            "order".to_string(),
+            alice,
+            operator,
+            Amount::from_tokens(6),
+            expires_at,
+            now,
+        )
+        .await
+        .expect("Failed to hold");
+    assert_invariants(&state).await;
+
+    // Only the four tokens left outside the hold can be spent
+    assert!(matches!(
+        state
+            .transfer(alice, bob, Amount::from_tokens(5), now, None, None)
+            .await,
+        Err(StateError::InsufficientAccountBalance { .. })
+    ));
+    assert!(matches!(
+        state
+            .hold(
+                "other".to_string(),
+                alice,
+                operator,
+                Amount::from_tokens(5),
+                expires_at,
+                now,
+            )
+            .await,
+        Err(StateError::InsufficientAccountBalance { .. })
+    ));
+    assert!(matches!(
+        state
+            .hold(
+                "order".to_string(),
+                alice,
+                operator,
+                Amount::ONE,
+                expires_at,
+                now,
+            )
+            .await,
+        Err(StateError::HoldExists(_))
+    ));
+
+    assert!(matches!(
+        state.capture_hold("order".to_string(), bob, bob).await,
+        Err(StateError::NotHoldOperator { .. })
+    ));
+    state
+        .capture_hold("order".to_string(), operator, bob)
+        .await
+        .expect("Failed to capture hold");
+    assert_eq!(
+        state.spendables.get(&bob).await.unwrap(),
+        Some(Amount::from_tokens(6))
+    );
+
+    // A settled hold can be neither captured nor released again
+    assert!(matches!(
+        state.capture_hold("order".to_string(), operator, bob).await,
+        Err(StateError::HoldNotFound(_))
+    ));
+    assert!(matches!(
+        state.release_hold("order".to_string(), operator).await,
+        Err(StateError::HoldNotFound(_))
+    ));
+    assert_eq!(state.held.get(&alice).await.unwrap(), None);
+    assert_invariants(&state).await;
+}
+
+#[tokio::test]
+async fn liquidation_releases_expired_holds() {
+    let (alice, bob, operator) = (owner("alice"), owner("bob"), owner("operator"));
+    let now = Timestamp::from(0);
+    let expires_at = now.saturating_add(TimeDelta::from_millis(1000));
+    let mut state = new_state(1_000_000).await;
+    state
+        .reward(alice, Amount::from_tokens(10), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+    state
+        .hold(
+            "order".to_string(),
+            alice,
+            operator,
+            Amount::from_tokens(6),
+            expires_at,
+            now,
+        )
+        .await
+        .expect("Failed to hold");
+
+    // Still held just before it expires
+    let before = expires_at.saturating_sub_micros(1);
+    state.liquidate(before).await.expect("Failed to liquidate");
+    assert!(state
+        .holds
+        .contains_key(&"order".to_string())
+        .await
+        .unwrap());
+
+    state
+        .liquidate(expires_at)
+        .await
+        .expect("Failed to liquidate");
+    assert!(!state
+        .holds
+        .contains_key(&"order".to_string())
+        .await
+        .unwrap());
+    assert_eq!(state.held.get(&alice).await.unwrap(), None);
+    assert_eq!(
+        state.spendables.get(&alice).await.unwrap(),
+        Some(Amount::from_tokens(10))
+    );
+    assert!(matches!(
+        state.capture_hold("order".to_string(), operator, bob).await,
+        Err(StateError::HoldNotFound(_))
+    ));
+    assert_invariants(&state).await;
+}
@@ -0,0 +1,110 @@
+//! Instantiation arguments and reward expiries checked against the application parameters.
+
+use credit_v2::{
+    instantiation_argument::InstantiationArgument, parameters::ApplicationParameters,
+    state::errors::StateError, types::RewardExpiry,
+};
+use linera_sdk::linera_base_types::{Amount, TimeDelta, Timestamp};
+
+fn parameters() -> ApplicationParameters {
+    ApplicationParameters {
+        default_ttl_ms: 1000,
+        max_ttl_ms: 5000,
+        decimals: 6,
+        ..ApplicationParameters::default()
+    }
+}
+
+#[test]
+fn zero_lifetime_takes_the_default() {
+    let argument = InstantiationArgument {
+        initial_supply: Amount::from_tokens(100),
+        amount_alive_ms: 0,
+    }
+    .with_defaults(&parameters())
+    .expect("Valid argument");
+
+    assert_eq!(argument.amount_alive_ms, 1000);
+    assert_eq!(argument.initial_supply, Amount::from_tokens(100));
+}
+
+#[test]
+fn invalid_arguments_are_rejected() {
+    let zero_supply = InstantiationArgument {
+        initial_supply: Amount::ZERO,
+        amount_alive_ms: 1000,
+    };
+    assert!(matches!(
+        zero_supply.with_defaults(&parameters()),
+        Err(StateError::InvalidInitialSupply)
+    ));
+
+    let too_long = InstantiationArgument {
+        initial_supply: Amount::ONE,
+        amount_alive_ms: 6000,
+    };
+    assert!(matches!(
+        too_long.with_defaults(&parameters()),
+        Err(StateError::InvalidTtl {
+            ttl_ms: 6000,
+            max_ttl_ms: 5000
+        })
+    ));
+
+    let no_lifetime = InstantiationArgument {
+        initial_supply: Amount::ONE,
+        amount_alive_ms: 0,
+    };
+    let parameters = ApplicationParameters {
+        default_ttl_ms: 0,
+        ..parameters()
+    };
+    assert!(matches!(
+        no_lifetime.with_defaults(&parameters),
+        Err(StateError::InvalidTtl { ttl_ms: 0, .. })
+    ));
+}
+
+#[test]
+fn reward_expiries_are_bounded_by_the_maximum_lifetime() {
+    let parameters = parameters();
+    let now = Timestamp::from(1_000_000);
+
+    for valid in [
+        RewardExpiry::TtlMs(5000),
+        RewardExpiry::At(now.saturating_add(TimeDelta::from_millis(5000))),
+        RewardExpiry::Never(true),
+    ] {
+        parameters.check_expiry(&valid, now).expect("Valid expiry");
+    }
+
+    assert!(matches!(
+        parameters.check_expiry(&RewardExpiry::TtlMs(5001), now),
+        Err(StateError::InvalidTtl {
+            ttl_ms: 5001,
+            max_ttl_ms: 5000
+        })
+    ));
+    assert!(matches!(
+        parameters.check_expiry(
+            &RewardExpiry::At(now.saturating_add(TimeDelta::from_millis(6000))),
+            now
+        ),
+        Err(StateError::InvalidTtl {
+            ttl_ms: 6000,
+            max_ttl_ms: 5000
+        })
+    ));
+    assert!(matches!(
+        parameters.check_expiry(&RewardExpiry::Never(false), now),
+        Err(StateError::InvalidNeverExpiry)
+    ));
+
+    let unbounded = ApplicationParameters {
+        max_ttl_ms: 0,
+        ..parameters
+    };
+    unbounded
+        .check_expiry(&RewardExpiry::TtlMs(u64::MAX), now)
+        .expect("Unbounded lifetime");
+}
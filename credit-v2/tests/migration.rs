@@ -0,0 +1,239 @@
+//! Importing a paginated state snapshot into an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use base::{interfaces::access_control::RoleStore, types::Role};
+use common::{empty_state, owner};
+use credit_v2::{
+    instantiation_argument::InstantiationArgument,
+    interfaces::state::{StateInterface, StateQueryInterface},
+    state::{errors::StateError, snapshot::records_hash},
+    types::{SnapshotPage, StateRecord},
+};
+use linera_sdk::linera_base_types::{Amount, Timestamp};
+
+fn page_records(page: &SnapshotPage) -> Vec<StateRecord> {
+    let bytes = hex::decode(&page.bcs).expect("Failed to decode page");
+    bcs::from_bytes(&bytes).expect("Failed to deserialize page")
+}
+
+#[tokio::test]
+async fn two_page_snapshot_is_consistent_once_finalized() {
+    let owners = [owner("alice"), owner("bob"), owner("carol")];
+    let now = Timestamp::from(0);
+    let mut source = empty_state().await;
+    source.instantiate(InstantiationArgument {
+        initial_supply: Amount::from_tokens(1000),
+        amount_alive_ms: 1_000_000,
+    });
+    for (i, owner) in owners.iter().enumerate() {
+        source
+            .reward(
+                *owner,
+                Amount::from_tokens(i as u128 + 1),
+                now,
+                None,
+                None,
+                None,
+            )
+            .await
+            .expect("Failed to reward");
+    }
+
+    // Three accounts and their three reward transactions
+    let first = source.snapshot(0, 3).await.expect("Failed to snapshot");
+    let offset = first.next_offset.expect("Missing second page");
+    let second = source
+        .snapshot(offset, 3)
+        .await
+        .expect("Failed to snapshot");
+    assert_eq!(second.next_offset, None);
+
+    let mut target = empty_state().await;
+    target
+        .import_snapshot(page_records(&first), first.hash.clone())
+        .await
+        .expect("Failed to import first page");
+    // The imported supply balance leaves out the accounts still to come
+    assert!(target.header.get().importing);
+    assert!(!target
+        .check_invariants()
+        .await
+        .expect("Failed to check invariants")
+        .is_empty());
+
+    target
+        .import_snapshot(page_records(&second), second.hash.clone())
+        .await
+        .expect("Failed to import second page");
+    assert!(!target.header.get().importing);
+    assert!(target
+        .check_invariants()
+        .await
+        .expect("Failed to check invariants")
+        .is_empty());
+    for owner in owners {
+        assert_eq!(
+            target
+                .alive_balance(owner, now)
+                .await
+                .expect("Failed to read balance"),
+            source
+                .alive_balance(owner, now)
+                .await
+                .expect("Failed to read balance"),
+        );
+    }
+}
+
+#[tokio::test]
+async fn snapshot_carries_history_and_detects_tampering() {
+    let (alice, admin) = (owner("alice"), owner("admin"));
+    let now = Timestamp::from(0);
+    let mut source = empty_state().await;
+    source.instantiate(InstantiationArgument {
+        initial_supply: Amount::from_tokens(1000),
+        amount_alive_ms: 1_000_000,
+    });
+    source
+        .set_roles(admin, vec![Role::Admin])
+        .expect("Failed to set roles");
+    source
+        .reward(alice, Amount::from_tokens(10), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+    source
+        .create_schedule(alice, Amount::ONE, 1000, 3, now)
+        .expect("Failed to create schedule");
+
+    let page = source.snapshot(0, 100).await.expect("Failed to snapshot");
+    assert_eq!(page.next_offset, None);
+    let records = page_records(&page);
+
+    let mut target = empty_state().await;
+    target
+        .import_snapshot(records.clone(), page.hash.clone())
+        .await
+        .expect("Failed to import snapshot");
+    assert_eq!(target.roles(admin).await.unwrap(), vec![Role::Admin]);
+    assert_eq!(target.schedules.count().await.unwrap(), 1);
+    assert_eq!(
+        *target.next_schedule_id.get(),
+        *source.next_schedule_id.get()
+    );
+    assert_eq!(target.transactions.count(), source.transactions.count());
+
+    // A changed record with a matching page hash still breaks the chained state hash
+    let mut tampered = records;
+    for record in &mut tampered {
+        if let StateRecord::Account(account) = record {
+            account.amounts[0].amount = Amount::from_tokens(9);
+            account.spendable = Amount::from_tokens(9);
+        }
+        if let StateRecord::Header(header) = record {
+            header.supply_balance = header.supply_balance.saturating_add(Amount::ONE);
+        }
+        if let StateRecord::Finalize(finalize) = record {
+            finalize.total_spendable = Amount::from_tokens(9);
+        }
+    }
+    let hash = records_hash(&tampered).expect("Failed to hash records");
+    let mut target = empty_state().await;
+    assert!(matches!(
+        target.import_snapshot(tampered, hash).await,
+        Err(StateError::SnapshotHashMismatch { .. })
+    ));
+}
+
+#[tokio::test]
+async fn open_import_rejects_every_mutation() {
+    let (alice, bob, admin) = (owner("alice"), owner("bob"), owner("admin"));
+    let now = Timestamp::from(0);
+    let mut source = empty_state().await;
+    source.instantiate(InstantiationArgument {
+        initial_supply: Amount::from_tokens(1000),
+        amount_alive_ms: 1_000_000,
+    });
+    for owner in [alice, bob] {
+        source
+            .reward(owner, Amount::from_tokens(1), now, None, None, None)
+            .await
+            .expect("Failed to reward");
+    }
+
+    let first = source.snapshot(0, 1).await.expect("Failed to snapshot");
+    assert!(first.next_offset.is_some());
+    let mut target = empty_state().await;
+    target
+        .import_snapshot(page_records(&first), first.hash.clone())
+        .await
+        .expect("Failed to import first page");
+    assert!(target.header.get().importing);
+
+    let later = Timestamp::from(1_000);
+    let results = vec![
+        ("mint", target.mint(admin, Amount::ONE, now)),
+        ("update_config", target.update_config(2_000, 0, admin, now)),
+        ("freeze", target.freeze(alice, false, admin, now)),
+        ("unfreeze", target.unfreeze(alice).await),
+        ("set_reward_callers", target.set_reward_callers(Vec::new())),
+        (
+            "set_transfer_callers",
+            target.set_transfer_callers(Vec::new()),
+        ),
+        (
+            "reward",
+            target
+                .reward(alice, Amount::ONE, now, None, None, None)
+                .await,
+        ),
+        (
+            "transfer",
+            target
+                .transfer(alice, bob, Amount::ONE, now, None, None)
+                .await,
+        ),
+        ("burn", target.burn(alice, Amount::ONE, now).await),
+        ("liquidate", target.liquidate(now).await),
+        (
+            "hold",
+            target
+                .hold("hold".to_string(), alice, admin, Amount::ONE, later, now)
+                .await,
+        ),
+        (
+            "capture_hold",
+            target
+                .capture_hold("hold".to_string(), admin, bob)
+                .await
+                .map(|_| ()),
+        ),
+        (
+            "release_hold",
+            target
+                .release_hold("hold".to_string(), admin)
+                .await
+                .map(|_| ()),
+        ),
+        (
+            "create_schedule",
+            target
+                .create_schedule(alice, Amount::ONE, 1000, 3, now)
+                .map(|_| ()),
+        ),
+        ("cancel_schedule", target.cancel_schedule(0).await),
+        (
+            "process_schedules",
+            target.process_schedules(10, now).await.map(|_| ()),
+        ),
+    ];
+    for (mutation, result) in results {
+        assert!(
+            matches!(result, Err(StateError::ImportInProgress)),
+            "{mutation} during an import: {result:?}"
+        );
+    }
+    assert!(target.header.get().importing);
+}
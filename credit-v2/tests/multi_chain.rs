@@ -0,0 +1,170 @@
+//! Multi-chain scenarios of credit together with leaderboard.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod scenario;
+
+use linera_sdk::linera_base_types::{Amount, TimeDelta};
+use scenario::{step, Scenario, Step};
+
+const AMOUNT_ALIVE_MS: u64 = 60_000;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rewards_rank_owners() {
+    let mut scenario = Scenario::new(3, AMOUNT_ALIVE_MS).await;
+
+    scenario
+        .run(vec![
+            step(Step::Reward {
+                user: 0,
+                amount: Amount::from_tokens(10),
+            })
+            .balance(0, Amount::from_tokens(10))
+            .rank(0, Some(1))
+            .rank(1, None),
+            step(Step::Reward {
+                user: 1,
+                amount: Amount::from_tokens(20),
+            })
+            .balance(1, Amount::from_tokens(20))
+            .rank(1, Some(1))
+            .rank(0, Some(2)),
+            step(Step::Reward {
+                user: 2,
+                amount: Amount::from_tokens(15),
+            })
+            .rank(1, Some(1))
+            .rank(2, Some(2))
+            .rank(0, Some(3)),
+        ])
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn transfers_from_user_chains_update_ranks() {
+    let mut scenario = Scenario::new(2, AMOUNT_ALIVE_MS).await;
+
+    scenario
+        .run(vec![
+            step(Step::Reward {
+                user: 0,
+                amount: Amount::from_tokens(10),
+            }),
+            step(Step::Reward {
+                user: 1,
+                amount: Amount::from_tokens(5),
+            })
+            .rank(0, Some(1))
+            .rank(1, Some(2)),
+            step(Step::Transfer {
+                from: 0,
+                to: 1,
+                amount: Amount::from_tokens(4),
+            })
+            .balance(0, Amount::from_tokens(6))
+            .balance(1, Amount::from_tokens(9))
+            .rank(1, Some(1))
+            .rank(0, Some(2)),
+        ])
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn amounts_expire_with_the_clock() {
+    let mut scenario = Scenario::new(2, AMOUNT_ALIVE_MS).await;
+
+    scenario
+        .run(vec![
+            step(Step::Reward {
+                user: 0,
+                amount: Amount::from_tokens(10),
+            }),
+            step(Step::Advance(TimeDelta::from_millis(AMOUNT_ALIVE_MS / 2))),
+            step(Step::Reward {
+                user: 1,
+                amount: Amount::from_tokens(5),
+            })
+            .balance(0, Amount::from_tokens(10))
+            .balance(1, Amount::from_tokens(5)),
+            step(Step::Advance(TimeDelta::from_millis(
+                AMOUNT_ALIVE_MS / 2 + 1,
+            )))
+            .balance(0, Amount::ZERO)
+            .balance(1, Amount::from_tokens(5)),
+            step(Step::Liquidate)
+                .balance(0, Amount::ZERO)
+                .balance(1, Amount::from_tokens(5)),
+            step(Step::Advance(TimeDelta::from_millis(AMOUNT_ALIVE_MS))).balance(1, Amount::ZERO),
+        ])
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rejected_transfer_keeps_balances_and_ranks() {
+    let mut scenario = Scenario::new(2, AMOUNT_ALIVE_MS).await;
+
+    // The forwarded transfer fails on the creator chain and bounces back to the user chain
+    scenario
+        .run(vec![
+            step(Step::Reward {
+                user: 0,
+                amount: Amount::from_tokens(3),
+            }),
+            step(Step::Reward {
+                user: 1,
+                amount: Amount::from_tokens(5),
+            }),
+            step(Step::Transfer {
+                from: 0,
+                to: 1,
+                amount: Amount::from_tokens(4),
+            })
+            .rejected()
+            .balance(0, Amount::from_tokens(3))
+            .balance(1, Amount::from_tokens(5))
+            .rank(1, Some(1))
+            .rank(0, Some(2)),
+            step(Step::Transfer {
+                from: 0,
+                to: 1,
+                amount: Amount::from_tokens(2),
+            })
+            .balance(0, Amount::ONE)
+            .balance(1, Amount::from_tokens(7))
+            .rank(1, Some(1))
+            .rank(0, Some(2)),
+        ])
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn banned_owner_keeps_credit_off_the_board() {
+    let mut scenario = Scenario::new(2, AMOUNT_ALIVE_MS).await;
+
+    scenario
+        .run(vec![
+            step(Step::Ban { user: 0 }),
+            step(Step::Reward {
+                user: 0,
+                amount: Amount::from_tokens(10),
+            })
+            .balance(0, Amount::from_tokens(10))
+            .rank(0, None),
+            step(Step::Reward {
+                user: 1,
+                amount: Amount::from_tokens(5),
+            })
+            .balance(1, Amount::from_tokens(5))
+            .rank(1, Some(1)),
+            step(Step::Transfer {
+                from: 0,
+                to: 1,
+                amount: Amount::from_tokens(4),
+            })
+            .balance(0, Amount::from_tokens(6))
+            .balance(1, Amount::from_tokens(9))
+            .rank(0, None)
+            .rank(1, Some(1)),
+        ])
+        .await;
+}
@@ -0,0 +1,202 @@
+//! Property testing of the credit state logic.
+//!
+//! Random sequences of reward, transfer and liquidate run against an in-memory state
+//! with a random clock, proptest shrinks a failing sequence to a minimal one.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use std::collections::BTreeSet;
+
+use common::{new_state, owner};
+use credit_v2::{
+    interfaces::state::StateInterface,
+    state::{errors::StateError, CreditState},
+};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, TimeDelta, Timestamp};
+use proptest::prelude::*;
+
+const OWNERS: usize = 4;
+const AMOUNT_ALIVE_MS: u64 = 1000;
+// The generated rewards may add up beyond the supply of 1000 tokens
+const MAX_STEPS: usize = 64;
+const MAX_AMOUNT: u128 = 100;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Reward {
+        owner: usize,
+        amount: u128,
+    },
+    Transfer {
+        from: usize,
+        to: usize,
+        amount: u128,
+    },
+    Liquidate,
+}
+
+/// An action and how far the clock moves before it
+#[derive(Clone, Debug)]
+struct Step {
+    advance_ms: u64,
+    action: Action,
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        (0..OWNERS, 1..=MAX_AMOUNT).prop_map(|(owner, amount)| Action::Reward { owner, amount }),
+        (0..OWNERS, 0..OWNERS, 1..=MAX_AMOUNT).prop_map(|(from, to, amount)| Action::Transfer {
+            from,
+            to,
+            amount
+        }),
+        Just(Action::Liquidate),
+    ]
+}
+
+fn steps() -> impl Strategy<Value = Vec<Step>> {
+    prop::collection::vec(
+        (0..AMOUNT_ALIVE_MS * 2, action())
+            .prop_map(|(advance_ms, action)| Step { advance_ms, action }),
+        1..MAX_STEPS,
+    )
+}
+
+fn owners() -> Vec<AccountOwner> {
+    (0..OWNERS).map(|i| owner(&format!("owner {i}"))).collect()
+}
+
+async fn spendable(state: &CreditState, owner: &AccountOwner) -> Amount {
+    state
+        .spendables
+        .get(owner)
+        .await
+        .expect("Failed to read spendable")
+        .unwrap_or_default()
+}
+
+async fn lots(state: &CreditState, owner: &AccountOwner) -> Vec<(Amount, Timestamp)> {
+    state
+        .balances
+        .get(owner)
+        .await
+        .expect("Failed to read balance")
+        .map(|amounts| {
+            amounts
+                .amounts
+                .into_iter()
+                .map(|lot| (lot.amount, lot.expired))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+async fn run(steps: Vec<Step>) -> Result<(), TestCaseError> {
+    let owners = owners();
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+    let mut now = Timestamp::from(0);
+    // Expiries stamped by rewards, transfers must only move lots between them
+    let mut expiries = BTreeSet::new();
+
+    for step in steps {
+        now = now.saturating_add(TimeDelta::from_millis(step.advance_ms));
+
+        match step.action {
+            Action::Reward { owner, amount } => {
+                let owner = owners[owner];
+                let before = spendable(&state, &owner).await;
+                let supply_balance = *state._balance.get();
+                let amount = Amount::from_tokens(amount);
+
+                let result = state.reward(owner, amount, now, None, None, None).await;
+
+                // A reward never takes more than the supply balance holds
+                if supply_balance < amount {
+                    prop_assert!(
+                        matches!(result, Err(StateError::InsufficientSupplyBalance { .. })),
+                        "{:?}",
+                        result
+                    );
+                    prop_assert_eq!(spendable(&state, &owner).await, before);
+                    prop_assert_eq!(*state._balance.get(), supply_balance);
+                } else {
+                    result.map_err(|e| TestCaseError::fail(e.to_string()))?;
+                    expiries.insert(now.saturating_add(TimeDelta::from_millis(AMOUNT_ALIVE_MS)));
+                    prop_assert_eq!(
+                        spendable(&state, &owner).await,
+                        before.saturating_add(amount)
+                    );
+                    prop_assert_eq!(*state._balance.get(), supply_balance.saturating_sub(amount));
+                }
+            }
+            Action::Transfer { from, to, amount } => {
+                let (from, to) = (owners[from], owners[to]);
+                let from_before = spendable(&state, &from).await;
+                let to_before = spendable(&state, &to).await;
+                let amount = Amount::from_tokens(amount);
+
+                let result = state.transfer(from, to, amount, now, None, None).await;
+
+                // A transfer never takes more than the sender holds
+                prop_assert_eq!(result.is_ok(), from_before >= amount);
+                let (from_after, to_after) =
+                    (spendable(&state, &from).await, spendable(&state, &to).await);
+                if result.is_err() || from == to {
+                    prop_assert_eq!(from_after, from_before);
+                    prop_assert_eq!(to_after, to_before);
+                } else {
+                    prop_assert_eq!(from_after, from_before.saturating_sub(amount));
+                    prop_assert_eq!(to_after, to_before.saturating_add(amount));
+                }
+            }
+            Action::Liquidate => {
+                let mut before = Vec::new();
+                for owner in &owners {
+                    before.push(spendable(&state, owner).await);
+                }
+
+                state
+                    .liquidate(now)
+                    .await
+                    .map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+                for (owner, before) in owners.iter().zip(before) {
+                    // Liquidation only removes credit, and every lot left is alive
+                    prop_assert!(spendable(&state, owner).await <= before);
+                    for (_, expired) in lots(&state, owner).await {
+                        prop_assert!(expired >= now);
+                    }
+                }
+            }
+        }
+
+        for owner in &owners {
+            for (amount, expired) in lots(&state, owner).await {
+                prop_assert!(amount > Amount::ZERO);
+                prop_assert!(expiries.contains(&expired));
+            }
+        }
+
+        let violations = state
+            .check_invariants()
+            .await
+            .map_err(|e| TestCaseError::fail(e.to_string()))?;
+        prop_assert!(violations.is_empty(), "{:?}", violations);
+    }
+
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn state_properties(steps in steps()) {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Failed to build runtime")
+            .block_on(run(steps))?;
+    }
+}
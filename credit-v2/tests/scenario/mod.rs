@@ -0,0 +1,275 @@
+//! Scenario DSL for multi-chain tests of credit together with leaderboard.
+//!
+//! A scenario creates both applications on a creator chain and opens `N` user chains,
+//! one owner each. Steps run on the chain the real flow would use, deliver the
+//! forwarded messages to the creator chain, then check the expected balances and
+//! ranks. A step expected to be rejected must fail its forwarded messages on the
+//! creator chain, which then rejects them back to the user chain. Time only moves on
+//! `Step::Advance`, so expiry is deterministic.
+
+use std::str::FromStr;
+
+use credit_v2::{
+    abi::{CreditAbi, Operation},
+    instantiation_argument::InstantiationArgument,
+    parameters::ApplicationParameters,
+};
+use leaderboard::{
+    abi::{LeaderBoardAbi, Operation as LeaderBoardOperation},
+    instantiation_argument::InstantiationArgument as LeaderBoardArgument,
+    state::types::AggregationMode,
+};
+use linera_sdk::{
+    linera_base_types::{AccountOwner, Amount, ApplicationId, TimeDelta},
+    test::{ActiveChain, MessageAction, QueryOutcome, TestValidator},
+};
+
+#[derive(Clone, Debug)]
+pub enum Step {
+    /// Rewards the owner of a user chain, requested from the creator chain
+    Reward { user: usize, amount: Amount },
+    /// Transfers between the owners of two user chains, requested from `from`
+    Transfer {
+        from: usize,
+        to: usize,
+        amount: Amount,
+    },
+    /// Liquidates the expired amounts, requested from the creator chain
+    Liquidate,
+    /// Bans the owner of a user chain from the leaderboard
+    Ban { user: usize },
+    /// Moves the validator clock forward
+    Advance(TimeDelta),
+}
+
+/// A step and what must hold once it's done
+#[derive(Clone, Debug)]
+pub struct Check {
+    step: Step,
+    rejected: bool,
+    balances: Vec<(usize, Amount)>,
+    ranks: Vec<(usize, Option<u32>)>,
+}
+
+pub fn step(step: Step) -> Check {
+    Check {
+        step,
+        rejected: false,
+        balances: Vec::new(),
+        ranks: Vec::new(),
+    }
+}
+
+impl Check {
+    /// Expects the creator chain to reject the messages forwarded by a transfer
+    pub fn rejected(mut self) -> Self {
+        self.rejected = true;
+        self
+    }
+
+    /// Expects the alive balance of the owner of `user`
+    pub fn balance(mut self, user: usize, amount: Amount) -> Self {
+        self.balances.push((user, amount));
+        self
+    }
+
+    /// Expects the leaderboard rank of the owner of `user`, `None` when not ranked
+    pub fn rank(mut self, user: usize, rank: Option<u32>) -> Self {
+        self.ranks.push((user, rank));
+        self
+    }
+}
+
+pub struct Scenario {
+    validator: TestValidator,
+    creator: ActiveChain,
+    users: Vec<ActiveChain>,
+    credit: ApplicationId<CreditAbi>,
+    leaderboard: ApplicationId<LeaderBoardAbi>,
+}
+
+impl Scenario {
+    pub async fn new(users: usize, amount_alive_ms: u64) -> Self {
+        let (validator, credit_module) = TestValidator::with_current_module::<
+            CreditAbi,
+            ApplicationParameters,
+            InstantiationArgument,
+        >()
+        .await;
+        let creator = validator.new_chain().await;
+
+        let leaderboard_module = creator
+            .publish_bytecode_files_in::<LeaderBoardAbi, (), LeaderBoardArgument>("../leaderboard")
+            .await;
+        let leaderboard = creator
+            .create_application(
+                leaderboard_module,
+                (),
+                LeaderBoardArgument {
+                    top_k: 10,
+                    aggregation_mode: AggregationMode::Replace,
+                },
+                vec![],
+            )
+            .await;
+
+        let credit = creator
+            .create_application(
+                credit_module,
+                ApplicationParameters {
+                    leaderboard_application_id: Some(leaderboard.forget_abi()),
+                    ..ApplicationParameters::default()
+                },
+                InstantiationArgument {
+                    initial_supply: Amount::from_tokens(1_000_000),
+                    amount_alive_ms,
+                },
+                vec![leaderboard.forget_abi()],
+            )
+            .await;
+
+        creator
+            .add_block(|block| {
+                block.with_operation(
+                    leaderboard,
+                    LeaderBoardOperation::UpdateCaller {
+                        caller: credit.forget_abi(),
+                    },
+                );
+            })
+            .await;
+
+        let mut chains = Vec::new();
+        for _ in 0..users {
+            chains.push(validator.new_chain().await);
+        }
+
+        Self {
+            validator,
+            creator,
+            users: chains,
+            credit,
+            leaderboard,
+        }
+    }
+
+    pub fn owner(&self, user: usize) -> AccountOwner {
+        AccountOwner::from(self.users[user].public_key())
+    }
+
+    /// Runs the checks in order, panicking on the first expectation which doesn't hold
+    pub async fn run(&mut self, checks: Vec<Check>) {
+        for (i, check) in checks.into_iter().enumerate() {
+            self.apply(check.step.clone(), check.rejected).await;
+
+            for (user, expected) in check.balances {
+                assert_eq!(
+                    self.balance(user).await,
+                    expected,
+                    "balance of user {user} after step {i}: {:?}",
+                    check.step
+                );
+            }
+            for (user, expected) in check.ranks {
+                assert_eq!(
+                    self.rank(user).await,
+                    expected,
+                    "rank of user {user} after step {i}: {:?}",
+                    check.step
+                );
+            }
+        }
+    }
+
+    async fn apply(&mut self, step: Step, rejected: bool) {
+        match step {
+            Step::Reward { user, amount } => {
+                let owner = self.owner(user);
+                self.operate_on_creator(Operation::Reward {
+                    owner,
+                    amount,
+                    expiry: None,
+                    memo: None,
+                    reference: None,
+                })
+                .await;
+            }
+            Step::Transfer { from, to, amount } => {
+                let operation = Operation::Transfer {
+                    from: self.owner(from),
+                    to: self.owner(to),
+                    amount,
+                    memo: None,
+                    reference: None,
+                };
+                let credit = self.credit;
+                let (certificate, _) = self.users[from]
+                    .add_block(|block| {
+                        block.with_operation(credit, operation);
+                    })
+                    .await;
+                if !rejected {
+                    self.creator.handle_received_messages().await;
+                    return;
+                }
+
+                // The failed message fails the block, a client then rejects it instead
+                assert!(
+                    self.creator
+                        .try_add_block(|block| {
+                            block.with_messages_from(&certificate);
+                        })
+                        .await
+                        .is_err(),
+                    "Forwarded transfer from user {from} wasn't rejected"
+                );
+                self.creator
+                    .add_block(|block| {
+                        block.with_messages_from_by_action(&certificate, MessageAction::Reject);
+                    })
+                    .await;
+                self.users[from].handle_received_messages().await;
+            }
+            Step::Liquidate => self.operate_on_creator(Operation::Liquidate).await,
+            Step::Ban { user } => {
+                let (leaderboard, owner) = (self.leaderboard, self.owner(user));
+                self.creator
+                    .add_block(|block| {
+                        block.with_operation(leaderboard, LeaderBoardOperation::BanOwner { owner });
+                    })
+                    .await;
+            }
+            Step::Advance(delta) => self.validator.clock().add(delta),
+        }
+    }
+
+    /// Runs an operation on the creator chain and executes the message it forwards there
+    async fn operate_on_creator(&mut self, operation: Operation) {
+        let credit = self.credit;
+        self.creator
+            .add_block(|block| {
+                block.with_operation(credit, operation);
+            })
+            .await;
+        self.creator.handle_received_messages().await;
+    }
+
+    async fn balance(&self, user: usize) -> Amount {
+        let query = format!("query {{ aliveBalance(owner: \"{}\") }}", self.owner(user));
+        let QueryOutcome { response, .. } = self.creator.graphql_query(self.credit, query).await;
+        let balance = response["aliveBalance"]
+            .as_str()
+            .expect("Missing alive balance");
+        Amount::from_str(balance).expect("Invalid alive balance")
+    }
+
+    async fn rank(&self, user: usize) -> Option<u32> {
+        let query = format!(
+            "query {{ rank(owner: \"{}\") {{ rank }} }}",
+            self.owner(user)
+        );
+        let QueryOutcome { response, .. } =
+            self.creator.graphql_query(self.leaderboard, query).await;
+        response["rank"]["rank"].as_u64().map(|rank| rank as u32)
+    }
+}
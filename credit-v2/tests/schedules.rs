@@ -0,0 +1,86 @@
+//! Scheduled rewards issued against an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{new_state, owner};
+use credit_v2::interfaces::state::StateInterface;
+use linera_sdk::linera_base_types::{Amount, TimeDelta, Timestamp};
+
+#[tokio::test]
+async fn skipped_schedules_do_not_count_toward_the_limit() {
+    let (alice, bob, carol, dave, admin) = (
+        owner("alice"),
+        owner("bob"),
+        owner("carol"),
+        owner("dave"),
+        owner("admin"),
+    );
+    let now = Timestamp::from(0);
+    let mut state = new_state(1_000_000).await;
+
+    // The first two due schedules can't be issued, a frozen owner and an unfunded amount
+    state
+        .create_schedule(alice, Amount::ONE, 1000, 1, now)
+        .expect("Failed to create schedule");
+    state
+        .freeze(alice, false, admin, now)
+        .expect("Failed to freeze");
+    state
+        .create_schedule(bob, Amount::from_tokens(2000), 1000, 1, now)
+        .expect("Failed to create schedule");
+    state
+        .create_schedule(carol, Amount::ONE, 1000, 1, now)
+        .expect("Failed to create schedule");
+    state
+        .create_schedule(dave, Amount::ONE, 1000, 1, now)
+        .expect("Failed to create schedule");
+
+    let results = state
+        .process_schedules(2, now)
+        .await
+        .expect("Failed to process schedules");
+    assert_eq!(
+        results
+            .iter()
+            .map(|result| result.owner)
+            .collect::<Vec<_>>(),
+        vec![carol, dave]
+    );
+    assert_eq!(state.schedules.count().await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn installments_follow_the_interval() {
+    let alice = owner("alice");
+    let start_at = Timestamp::from(0);
+    let mut state = new_state(1_000_000).await;
+    let schedule_id = state
+        .create_schedule(alice, Amount::ONE, 1000, 2, start_at)
+        .expect("Failed to create schedule");
+
+    let issued = state
+        .process_schedules(10, start_at)
+        .await
+        .expect("Failed to process schedules");
+    assert_eq!(issued.len(), 1);
+    assert_eq!(issued[0].balance, Amount::ONE);
+
+    // The next installment isn't due before the interval elapsed
+    let early = start_at.saturating_add(TimeDelta::from_millis(999));
+    assert!(state
+        .process_schedules(10, early)
+        .await
+        .expect("Failed to process schedules")
+        .is_empty());
+
+    let due = start_at.saturating_add(TimeDelta::from_millis(1000));
+    let issued = state
+        .process_schedules(10, due)
+        .await
+        .expect("Failed to process schedules");
+    assert_eq!(issued.len(), 1);
+    assert_eq!(issued[0].balance, Amount::from_tokens(2));
+    assert!(!state.schedules.contains_key(&schedule_id).await.unwrap());
+}
@@ -0,0 +1,115 @@
+//! Minting and burning credit against an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{new_state, owner};
+use credit_v2::{
+    interfaces::state::StateInterface, state::errors::StateError, types::SupplyChangeKind,
+};
+use linera_sdk::linera_base_types::{Amount, TimeDelta, Timestamp};
+
+const AMOUNT_ALIVE_MS: u64 = 1000;
+
+#[tokio::test]
+async fn mint_grows_the_supply_balance() {
+    let admin = owner("admin");
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    state
+        .mint(admin, Amount::from_tokens(50), Timestamp::from(0))
+        .expect("Failed to mint");
+    assert_eq!(*state._initial_supply.get(), Amount::from_tokens(1050));
+    assert_eq!(*state._balance.get(), Amount::from_tokens(1050));
+
+    let history = state
+        .supply_history
+        .read(..)
+        .await
+        .expect("Failed to read supply history");
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].kind, SupplyChangeKind::Mint);
+    assert_eq!(history[0].initial_supply, Amount::from_tokens(1050));
+    assert!(state
+        .check_invariants()
+        .await
+        .expect("Failed to check invariants")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn burn_only_takes_alive_lots() {
+    let alice = owner("alice");
+    let rewarded_at = Timestamp::from(0);
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    state
+        .reward(alice, Amount::from_tokens(3), rewarded_at, None, None, None)
+        .await
+        .expect("Failed to reward");
+    let later = rewarded_at.saturating_add(TimeDelta::from_millis(AMOUNT_ALIVE_MS / 2));
+    state
+        .reward(alice, Amount::from_tokens(4), later, None, None, None)
+        .await
+        .expect("Failed to reward");
+
+    // The first lot expired but wasn't liquidated yet
+    let now = rewarded_at.saturating_add(TimeDelta::from_millis(AMOUNT_ALIVE_MS + 1));
+    assert!(matches!(
+        state.burn(alice, Amount::from_tokens(5), now).await,
+        Err(StateError::InsufficientAccountBalance { available, .. })
+            if available == Amount::from_tokens(4)
+    ));
+
+    state
+        .burn(alice, Amount::from_tokens(4), now)
+        .await
+        .expect("Failed to burn");
+    assert_eq!(*state._initial_supply.get(), Amount::from_tokens(996));
+    // The expired lot went back to the supply balance instead of being burnt
+    assert_eq!(*state._balance.get(), Amount::from_tokens(996));
+    assert_eq!(
+        state
+            .alive_balance(alice, now)
+            .await
+            .expect("Failed to read balance"),
+        Amount::ZERO
+    );
+    assert!(state
+        .check_invariants()
+        .await
+        .expect("Failed to check invariants")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn rewards_beyond_the_supply_balance_are_rejected() {
+    let alice = owner("alice");
+    let now = Timestamp::from(0);
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    state
+        .reward(alice, Amount::from_tokens(600), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+    assert!(matches!(
+        state
+            .reward(alice, Amount::from_tokens(401), now, None, None, None)
+            .await,
+        Err(StateError::InsufficientSupplyBalance { required, available })
+            if required == Amount::from_tokens(401) && available == Amount::from_tokens(400)
+    ));
+
+    // Nothing of the rejected reward was written
+    assert_eq!(
+        state.spendables.get(&alice).await.unwrap(),
+        Some(Amount::from_tokens(600))
+    );
+    assert_eq!(*state._balance.get(), Amount::from_tokens(400));
+    assert!(state
+        .check_invariants()
+        .await
+        .expect("Failed to check invariants")
+        .is_empty());
+}
@@ -0,0 +1,173 @@
+//! Transaction history, memos and external references against an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{new_state, owner};
+use credit_v2::{
+    interfaces::state::{StateInterface, StateQueryInterface},
+    state::errors::StateError,
+    types::{TransactionKind, MAX_MEMO_LENGTH},
+};
+use linera_sdk::linera_base_types::{Amount, CryptoHash, Timestamp};
+
+const AMOUNT_ALIVE_MS: u64 = 1000;
+
+#[tokio::test]
+async fn rewards_and_transfers_are_logged_in_order() {
+    let (alice, bob) = (owner("alice"), owner("bob"));
+    let order = CryptoHash::test_hash("order-1");
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    state
+        .reward(
+            alice,
+            Amount::from_tokens(10),
+            Timestamp::from(0),
+            None,
+            Some("signup".to_string()),
+            None,
+        )
+        .await
+        .expect("Failed to reward");
+    state
+        .transfer(
+            alice,
+            bob,
+            Amount::from_tokens(4),
+            Timestamp::from(5),
+            Some("order payment".to_string()),
+            Some(order),
+        )
+        .await
+        .expect("Failed to transfer");
+
+    let log = state
+        .transactions(0, 10)
+        .await
+        .expect("Failed to read transactions");
+    assert_eq!(log.len(), 2);
+
+    assert_eq!(log[0].kind, TransactionKind::Reward);
+    assert_eq!(log[0].from, None);
+    assert_eq!(log[0].to, alice);
+    assert_eq!(log[0].amount, Amount::from_tokens(10));
+    assert_eq!(log[0].memo.as_deref(), Some("signup"));
+    assert_eq!(log[0].reference, None);
+
+    assert_eq!(log[1].kind, TransactionKind::Transfer);
+    assert_eq!(log[1].from, Some(alice));
+    assert_eq!(log[1].to, bob);
+    assert_eq!(log[1].amount, Amount::from_tokens(4));
+    assert_eq!(log[1].memo.as_deref(), Some("order payment"));
+    assert_eq!(log[1].reference, Some(order));
+    assert_eq!(log[1].timestamp, Timestamp::from(5));
+
+    // Pages past the end are empty rather than failing
+    let page = state
+        .transactions(1, 10)
+        .await
+        .expect("Failed to read transactions");
+    assert_eq!(page.len(), 1);
+    assert!(state
+        .transactions(5, 10)
+        .await
+        .expect("Failed to read transactions")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn transactions_are_found_by_reference() {
+    let (alice, bob) = (owner("alice"), owner("bob"));
+    let (order, other) = (
+        CryptoHash::test_hash("order-1"),
+        CryptoHash::test_hash("order-2"),
+    );
+    let now = Timestamp::from(0);
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    state
+        .reward(alice, Amount::from_tokens(10), now, None, None, Some(order))
+        .await
+        .expect("Failed to reward");
+    state
+        .transfer(alice, bob, Amount::from_tokens(1), now, None, Some(other))
+        .await
+        .expect("Failed to transfer");
+    state
+        .transfer(alice, bob, Amount::from_tokens(2), now, None, Some(order))
+        .await
+        .expect("Failed to transfer");
+
+    let found = state
+        .transactions_by_reference(order)
+        .await
+        .expect("Failed to read transactions");
+    assert_eq!(
+        found
+            .iter()
+            .map(|transaction| (transaction.kind, transaction.amount))
+            .collect::<Vec<_>>(),
+        vec![
+            (TransactionKind::Reward, Amount::from_tokens(10)),
+            (TransactionKind::Transfer, Amount::from_tokens(2)),
+        ]
+    );
+    assert!(state
+        .transactions_by_reference(CryptoHash::test_hash("unknown"))
+        .await
+        .expect("Failed to read transactions")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn memo_length_is_bounded() {
+    let (alice, bob) = (owner("alice"), owner("bob"));
+    let now = Timestamp::from(0);
+    let mut state = new_state(AMOUNT_ALIVE_MS).await;
+
+    state
+        .reward(
+            alice,
+            Amount::from_tokens(10),
+            now,
+            None,
+            Some("a".repeat(MAX_MEMO_LENGTH)),
+            None,
+        )
+        .await
+        .expect("Failed to reward with the longest memo");
+
+    let long = Some("a".repeat(MAX_MEMO_LENGTH + 1));
+    assert!(matches!(
+        state
+            .reward(alice, Amount::from_tokens(1), now, None, long.clone(), None)
+            .await,
+        Err(StateError::MemoTooLong { length, max })
+            if length == MAX_MEMO_LENGTH + 1 && max == MAX_MEMO_LENGTH
+    ));
+    assert!(matches!(
+        state
+            .transfer(alice, bob, Amount::from_tokens(1), now, long, None)
+            .await,
+        Err(StateError::MemoTooLong { .. })
+    ));
+
+    // Nothing of the rejected operations is logged or moved
+    assert_eq!(
+        state
+            .transactions(0, 10)
+            .await
+            .expect("Failed to read transactions")
+            .len(),
+        1
+    );
+    assert_eq!(
+        state
+            .alive_balance(bob, now)
+            .await
+            .expect("Failed to read balance"),
+        Amount::ZERO
+    );
+}
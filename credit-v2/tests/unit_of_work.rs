@@ -0,0 +1,79 @@
+//! Rollback of a failed handler's writes against an in-memory credit state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use std::{cell::RefCell, rc::Rc};
+
+use base::{interfaces::unit_of_work::UnitOfWork, runtime::unit_of_work::ViewUnitOfWork};
+use common::{new_state, owner};
+use credit_v2::{interfaces::state::StateInterface, state::adapter::StateAdapter};
+use linera_sdk::linera_base_types::{Amount, Timestamp};
+
+#[tokio::test]
+async fn rollback_keeps_writes_staged_before_begin() {
+    let (alice, bob) = (owner("alice"), owner("bob"));
+    let now = Timestamp::from(0);
+    let state = Rc::new(RefCell::new(new_state(1_000_000).await));
+    let mut adapter = StateAdapter::new(state.clone());
+
+    // Staged by an earlier call in the same transaction
+    adapter
+        .reward(alice, Amount::from_tokens(10), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+
+    let mut unit_of_work = ViewUnitOfWork::new(state.clone());
+    unit_of_work.begin().expect("Failed to begin");
+    adapter
+        .reward(bob, Amount::from_tokens(5), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+    adapter
+        .transfer(alice, bob, Amount::from_tokens(3), now, None, None)
+        .await
+        .expect("Failed to transfer");
+    unit_of_work.rollback();
+
+    assert_eq!(
+        adapter
+            .alive_balance(alice, now)
+            .await
+            .expect("Failed to read balance"),
+        Amount::from_tokens(10)
+    );
+    assert_eq!(
+        adapter
+            .alive_balance(bob, now)
+            .await
+            .expect("Failed to read balance"),
+        Amount::ZERO
+    );
+}
+
+#[tokio::test]
+async fn commit_keeps_the_unit_writes() {
+    let alice = owner("alice");
+    let now = Timestamp::from(0);
+    let state = Rc::new(RefCell::new(new_state(1_000_000).await));
+    let mut adapter = StateAdapter::new(state.clone());
+    let mut unit_of_work = ViewUnitOfWork::new(state.clone());
+
+    unit_of_work.begin().expect("Failed to begin");
+    adapter
+        .reward(alice, Amount::from_tokens(10), now, None, None, None)
+        .await
+        .expect("Failed to reward");
+    unit_of_work.commit();
+    // Nothing to restore once committed
+    unit_of_work.rollback();
+
+    assert_eq!(
+        adapter
+            .alive_balance(alice, now)
+            .await
+            .expect("Failed to read balance"),
+        Amount::from_tokens(10)
+    );
+}
@@ -1,4 +1,5 @@
 use async_graphql::{Request, Response};
+use base::{handler::errors::HandlerError, types::Role};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
     linera_base_types::{AccountOwner, Amount, ApplicationId, ContractAbi, ServiceAbi},
@@ -7,7 +8,7 @@ use serde::{Deserialize, Serialize};
 
 pub struct LeaderBoardAbi;
 
-pub type OperationResponse = ();
+pub type OperationResponse = Result<(), HandlerError>;
 
 impl ContractAbi for LeaderBoardAbi {
     type Operation = Operation;
@@ -36,9 +37,32 @@ pub enum Operation {
     UpdateCaller {
         caller: ApplicationId,
     },
+    RemoveEntry {
+        owner: AccountOwner,
+    },
+    ResetBoard,
+    /// Rebuilds the ordered index from the stored values, e.g. after an upgrade
+    RebuildRanking,
+    BanOwner {
+        owner: AccountOwner,
+    },
+    UnbanOwner {
+        owner: AccountOwner,
+    },
+    GrantRole {
+        owner: AccountOwner,
+        role: Role,
+    },
+    RevokeRole {
+        owner: AccountOwner,
+        role: Role,
+    },
+    RenounceRole {
+        role: Role,
+    },
 }
 
-/// No message needed
+/// Operator lifecycle requests, handled on the creation chain like their operations
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum Message {
     ProposeOperator { operator: AccountOwner },
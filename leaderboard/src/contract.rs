@@ -2,6 +2,7 @@
 
 use std::{cell::RefCell, rc::Rc};
 
+use base::{interfaces::access_control::RoleStore, types::Role};
 use leaderboard::{
     abi::{LeaderBoardAbi, Message, Operation},
     instantiation_argument::InstantiationArgument,
@@ -44,6 +45,17 @@ impl Contract for LeaderBoardContract {
     async fn instantiate(&mut self, argument: InstantiationArgument) {
         self.runtime.borrow_mut().application_parameters();
         self.state.borrow_mut().instantiate(argument);
+
+        // Creator is the first admin, who grants the other roles
+        let owner = self
+            .runtime
+            .borrow_mut()
+            .authenticated_signer()
+            .expect("Instantiation requires an authenticated signer");
+        self.state
+            .borrow_mut()
+            .set_roles(owner, vec![Role::Admin])
+            .expect("Failed to grant admin role");
     }
 
     async fn execute_operation(&mut self, operation: Operation) -> Self::Response {
@@ -51,7 +63,7 @@ impl Contract for LeaderBoardContract {
     }
 
     async fn execute_message(&mut self, message: Message) {
-        self.on_message(&message)
+        self.on_message(&message).await
     }
 
     async fn store(self) {
@@ -1,37 +1,48 @@
 use super::LeaderBoardContract;
 
+use base::handler::{errors::HandlerError, executor};
 use leaderboard::{
     abi::{Message, Operation, OperationResponse},
-    contract_inner::handlers::HandlerFactory,
-    runtime::contract::ContractRuntimeAdapter,
+    contract_inner::handlers::{types::HandlerOutcome, HandlerFactory},
     state::adapter::StateAdapter,
 };
 
 impl LeaderBoardContract {
     pub async fn on_op(&mut self, op: &Operation) -> OperationResponse {
-        let runtime_context = ContractRuntimeAdapter::new(self.runtime.clone());
-        let state_adapter = StateAdapter::new(self.state.clone());
-
-        let _outcome = match HandlerFactory::new(runtime_context, state_adapter, Some(op), None)
-            .unwrap()
-            .handle()
-            .await
-        {
-            Ok(outcome) => outcome,
-            Err(err) => panic!("Failed OP: {:?}: {err}", op),
-        };
-
-        // There won't be any message or event here
-        ()
+        match self.execute(Some(op), None).await {
+            Ok(_outcome) => Ok(()),
+            Err(err) => {
+                log::warn!("Failed OP: {:?}: {err}", op);
+                Err(err)
+            }
+        }
     }
 
-    pub fn on_message(&mut self, msg: &Message) {
-        let runtime_context = ContractRuntimeAdapter::new(self.runtime.clone());
-        let state_adapter = StateAdapter::new(self.state.clone());
+    pub async fn on_message(&mut self, msg: &Message) {
+        // A rejected message comes back to its sender, where there's nothing to redo
+        if self.runtime.borrow_mut().message_is_bouncing() == Some(true) {
+            log::warn!("Bounced MSG {:?}", msg);
+            return;
+        }
+        // The state is rolled back, failing the message gets it rejected back to its sender
+        if let Err(err) = self.execute(None, Some(msg)).await {
+            panic!("Failed MSG {:?}: {err}", msg);
+        }
+    }
 
-        let _outcome = match HandlerFactory::new(runtime_context, state_adapter, None, Some(msg)) {
-            Ok(outcome) => outcome,
-            Err(err) => panic!("Failed MSG {:?}: {err}", msg),
-        };
+    /// Runs the handler as one unit of work
+    async fn execute(
+        &mut self,
+        op: Option<&Operation>,
+        msg: Option<&Message>,
+    ) -> Result<HandlerOutcome, HandlerError> {
+        executor::execute::<HandlerFactory, _, _, _>(
+            self.runtime.clone(),
+            self.state.clone(),
+            StateAdapter::new(self.state.clone()),
+            op,
+            msg,
+        )
+        .await
     }
 }
@@ -1,2 +1,3 @@
 pub mod errors;
 pub mod handlers;
+pub mod operator;
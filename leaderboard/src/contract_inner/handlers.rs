@@ -1,82 +1,55 @@
-pub mod errors;
-pub mod interfaces;
+pub mod message;
 pub mod operation;
 pub mod types;
 
 use crate::abi::{Message, Operation};
-use crate::interfaces::{
-    access_control::AccessControl, runtime::contract::ContractRuntimeContext, state::StateInterface,
+use crate::interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface};
+use base::{handler::roles, interfaces::access_control::AccessControl};
+use base_macros::env_handler;
+use message::{
+    approve_operator::ApproveOperatorMessageHandler,
+    confirm_operator::ConfirmOperatorMessageHandler,
+    propose_operator::ProposeOperatorMessageHandler, reject_operator::RejectOperatorMessageHandler,
+    update_caller::UpdateCallerMessageHandler,
+};
+use operation::{
+    approve_operator::ApproveOperatorHandler, ban_owner::BanOwnerHandler,
+    confirm_operator::ConfirmOperatorHandler, propose_operator::ProposeOperatorHandler,
+    rebuild_ranking::RebuildRankingHandler, reject_operator::RejectOperatorHandler,
+    remove_entry::RemoveEntryHandler, reset_board::ResetBoardHandler,
+    unban_owner::UnbanOwnerHandler, update_caller::UpdateCallerHandler,
+    update_value::UpdateValueHandler,
 };
-use errors::HandlerError;
-use interfaces::Handler;
-use operation::update_value::UpdateValueHandler;
-
-pub struct HandlerFactory;
-
-impl HandlerFactory {
-    fn new_operation_handler(
-        runtime: impl ContractRuntimeContext + AccessControl + 'static,
-        state: impl StateInterface + 'static,
-        op: &Operation,
-    ) -> Box<dyn Handler> {
-        match op {
-            Operation::UpdateValue { owner, value } => {
-                Box::new(UpdateValueHandler::new(runtime, state, owner, value))
-            }
-            Operation::ProposeOperator { operator } => {
-                unimplemented!()
-            }
-            Operation::ApproveOperator => {
-                unimplemented!()
-            }
-            Operation::RejectOperator => {
-                unimplemented!()
-            }
-            Operation::ConfirmOperator => {
-                unimplemented!()
-            }
-            Operation::UpdateCaller { caller } => {
-                unimplemented!()
-            }
-        }
-    }
 
-    fn new_message_handler(
-        _runtime: impl ContractRuntimeContext + AccessControl,
-        _state: impl StateInterface,
-        msg: &Message,
-    ) -> Box<dyn Handler> {
-        match msg {
-            Message::ProposeOperator { operator } => {
-                unimplemented!()
-            }
-            Message::ApproveOperator => {
-                unimplemented!()
-            }
-            Message::RejectOperator => {
-                unimplemented!()
-            }
-            Message::ConfirmOperator => {
-                unimplemented!()
-            }
-            Message::UpdateCaller { caller } => {
-                unimplemented!()
-            }
-        }
-    }
+type GrantRoleHandler<R, S> = roles::GrantRoleHandler<R, S, Message>;
+type RevokeRoleHandler<R, S> = roles::RevokeRoleHandler<R, S, Message>;
+type RenounceRoleHandler<R, S> = roles::RenounceRoleHandler<R, S, Message>;
 
-    pub fn new(
-        runtime: impl ContractRuntimeContext + AccessControl + 'static,
-        state: impl StateInterface + 'static,
-        op: Option<&Operation>,
-        msg: Option<&Message>,
-    ) -> Result<Box<dyn Handler>, HandlerError> {
-        if let Some(op) = op {
-            return Ok(HandlerFactory::new_operation_handler(runtime, state, op));
-        }
-        if let Some(msg) = msg {
-            return Ok(HandlerFactory::new_message_handler(runtime, state, msg));
-        }
-        Err(HandlerError::InvalidOperationAndMessage)
-    }
-}
+#[env_handler(
+    runtime = ContractRuntimeContext + AccessControl,
+    state = StateInterface,
+    operation = Operation {
+        UpdateValue => UpdateValueHandler,
+        ProposeOperator => ProposeOperatorHandler,
+        ApproveOperator => ApproveOperatorHandler,
+        RejectOperator => RejectOperatorHandler,
+        ConfirmOperator => ConfirmOperatorHandler,
+        UpdateCaller => UpdateCallerHandler,
+        RemoveEntry => RemoveEntryHandler,
+        ResetBoard => ResetBoardHandler,
+        RebuildRanking => RebuildRankingHandler,
+        BanOwner => BanOwnerHandler,
+        UnbanOwner => UnbanOwnerHandler,
+        GrantRole { owner, role } => GrantRoleHandler,
+        RevokeRole { owner, role } => RevokeRoleHandler,
+        RenounceRole { role } => RenounceRoleHandler,
+    },
+    message = Message {
+        ProposeOperator => ProposeOperatorMessageHandler,
+        ApproveOperator => ApproveOperatorMessageHandler,
+        RejectOperator => RejectOperatorMessageHandler,
+        ConfirmOperator => ConfirmOperatorMessageHandler,
+        UpdateCaller => UpdateCallerMessageHandler,
+    },
+)]
+pub struct HandlerFactory;
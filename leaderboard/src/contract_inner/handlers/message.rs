@@ -1 +1,5 @@
-pub mod transfer;
+pub mod approve_operator;
+pub mod confirm_operator;
+pub mod propose_operator;
+pub mod reject_operator;
+pub mod update_caller;
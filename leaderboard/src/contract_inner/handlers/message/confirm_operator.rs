@@ -0,0 +1,30 @@
+use crate::{
+    abi::Message,
+    contract_inner::{handlers::types::HandlerOutcome, operator},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Message::ConfirmOperator)]
+#[handler]
+pub struct ConfirmOperatorMessageHandler<
+    R: ContractRuntimeContext + AccessControl,
+    S: StateInterface,
+> {
+    runtime: R,
+    state: S,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for ConfirmOperatorMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        operator::confirm_operator(&mut self.runtime, &mut self.state).await?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    abi::Message,
+    contract_inner::{handlers::types::HandlerOutcome, operator},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::AccountOwner;
+
+#[operation_handler(Message::ProposeOperator)]
+#[handler]
+pub struct ProposeOperatorMessageHandler<
+    R: ContractRuntimeContext + AccessControl,
+    S: StateInterface,
+> {
+    runtime: R,
+    state: S,
+
+    operator: AccountOwner,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for ProposeOperatorMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        operator::propose_operator(&mut self.runtime, &mut self.state, self.operator).await?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    abi::Message,
+    contract_inner::{handlers::types::HandlerOutcome, operator},
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::ApplicationId;
+
+#[operation_handler(Message::UpdateCaller)]
+#[handler]
+pub struct UpdateCallerMessageHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+{
+    runtime: R,
+    _state: S,
+
+    caller: ApplicationId,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for UpdateCallerMessageHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        operator::update_caller(&mut self.runtime, self.caller).await?;
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -1 +1,11 @@
+pub mod approve_operator;
+pub mod ban_owner;
+pub mod confirm_operator;
+pub mod propose_operator;
+pub mod rebuild_ranking;
+pub mod reject_operator;
+pub mod remove_entry;
+pub mod reset_board;
+pub mod unban_owner;
+pub mod update_caller;
 pub mod update_value;
@@ -0,0 +1,48 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    state::types::{AuditAction, AuditRecord},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base::types::Role;
+use base_macros::{handler, operation_handler};
+
+#[operation_handler(Operation::RebuildRanking)]
+#[handler]
+pub struct RebuildRankingHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for RebuildRankingHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+        self.runtime
+            .only_role(Role::Operator)
+            .await
+            .map_err(HandlerError::runtime)?;
+
+        self.state
+            .rebuild_ranking()
+            .await
+            .map_err(HandlerError::state)?;
+
+        let record = AuditRecord {
+            action: AuditAction::RebuildRanking,
+            owner: None,
+            operator: self.runtime.authenticated_signer(),
+            timestamp: self.runtime.system_time(),
+        };
+        self.state.append_audit(record);
+
+        Ok(HandlerOutcome::default())
+    }
+}
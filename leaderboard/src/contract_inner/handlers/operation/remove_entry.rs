@@ -0,0 +1,52 @@
+use crate::{
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
+    state::types::{AuditAction, AuditRecord},
+};
+use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base::types::Role;
+use base_macros::{handler, operation_handler};
+
+use linera_sdk::linera_base_types::AccountOwner;
+
+#[operation_handler(Operation::RemoveEntry)]
+#[handler]
+pub struct RemoveEntryHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: R,
+    state: S,
+
+    owner: AccountOwner,
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
+    for RemoveEntryHandler<R, S>
+{
+    async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
+        self.runtime
+            .only_application_creator()
+            .map_err(HandlerError::runtime)?;
+        self.runtime
+            .only_role(Role::Operator)
+            .await
+            .map_err(HandlerError::runtime)?;
+
+        self.state
+            .remove_entry(self.owner)
+            .await
+            .map_err(HandlerError::state)?;
+
+        let record = AuditRecord {
+            action: AuditAction::RemoveEntry,
+            owner: Some(self.owner),
+            operator: self.runtime.authenticated_signer(),
+            timestamp: self.runtime.system_time(),
+        };
+        self.state.append_audit(record);
+
+        Ok(HandlerOutcome::default())
+    }
+}
@@ -1,14 +1,18 @@
 use crate::{
-    contract_inner::handlers::{errors::HandlerError, interfaces::Handler, types::HandlerOutcome},
-    interfaces::{
-        access_control::AccessControl, runtime::contract::ContractRuntimeContext,
-        state::StateInterface,
-    },
+    abi::{Message, Operation},
+    contract_inner::handlers::types::HandlerOutcome,
+    interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface},
 };
 use async_trait::async_trait;
+use base::handler::{errors::HandlerError, interfaces::Handler};
+use base::interfaces::access_control::AccessControl;
+use base::types::Role;
+use base_macros::{handler, operation_handler};
 
 use linera_sdk::linera_base_types::{AccountOwner, Amount};
 
+#[operation_handler(Operation::UpdateValue)]
+#[handler]
 pub struct UpdateValueHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
     runtime: R,
     state: S,
@@ -17,34 +21,25 @@ pub struct UpdateValueHandler<R: ContractRuntimeContext + AccessControl, S: Stat
     value: Amount,
 }
 
-impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> UpdateValueHandler<R, S> {
-    pub fn new(runtime: R, state: S, owner: &AccountOwner, value: &Amount) -> Self {
-        Self {
-            state,
-            runtime,
-
-            owner: *owner,
-            value: *value,
-        }
-    }
-}
-
 #[async_trait(?Send)]
-impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> Handler<Message>
     for UpdateValueHandler<R, S>
 {
     async fn handle(&mut self) -> Result<HandlerOutcome, HandlerError> {
         self.runtime
             .only_application_creator()
-            .map_err(|e| HandlerError::RuntimeError(Box::new(e)))?;
+            .map_err(HandlerError::runtime)?;
+        self.runtime
+            .only_role(Role::Caller)
+            .await
+            .map_err(HandlerError::runtime)?;
 
         let now = self.runtime.system_time();
         self.state
             .update_value(self.owner, self.value, now)
-            .map_err(|e| HandlerError::RuntimeError(Box::new(e)))?;
+            .await
+            .map_err(HandlerError::state)?;
 
-        Ok(HandlerOutcome {
-            messages: Vec::new(),
-        })
+        Ok(HandlerOutcome::default())
     }
 }
@@ -1,6 +1,3 @@
 use crate::abi::Message;
 
-#[derive(Debug, Default)]
-pub struct HandlerOutcome {
-    pub messages: Vec<Message>,
-}
+pub type HandlerOutcome = base::handler::types::HandlerOutcome<Message>;
@@ -0,0 +1,83 @@
+//! Operator lifecycle shared by the operation and message handlers: an admin
+//! proposes a candidate, the candidate approves or rejects, and an admin confirms.
+
+use crate::interfaces::{runtime::contract::ContractRuntimeContext, state::StateInterface};
+use base::{handler::errors::HandlerError, interfaces::access_control::AccessControl, types::Role};
+use linera_sdk::linera_base_types::{AccountOwner, ApplicationId};
+
+pub async fn propose_operator<R: ContractRuntimeContext + AccessControl, S: StateInterface>(
+    runtime: &mut R,
+    state: &mut S,
+    operator: AccountOwner,
+) -> Result<(), HandlerError> {
+    runtime
+        .only_application_creator()
+        .map_err(HandlerError::runtime)?;
+    runtime
+        .only_role(Role::Admin)
+        .await
+        .map_err(HandlerError::runtime)?;
+
+    state.propose_operator(operator);
+    Ok(())
+}
+
+pub fn approve_operator<R: ContractRuntimeContext + AccessControl, S: StateInterface>(
+    runtime: &mut R,
+    state: &mut S,
+) -> Result<(), HandlerError> {
+    runtime
+        .only_application_creator()
+        .map_err(HandlerError::runtime)?;
+    let signer = runtime
+        .require_authenticated_signer()
+        .map_err(HandlerError::runtime)?;
+
+    state.approve_operator(signer).map_err(HandlerError::state)
+}
+
+pub fn reject_operator<R: ContractRuntimeContext + AccessControl, S: StateInterface>(
+    runtime: &mut R,
+    state: &mut S,
+) -> Result<(), HandlerError> {
+    runtime
+        .only_application_creator()
+        .map_err(HandlerError::runtime)?;
+    let signer = runtime
+        .require_authenticated_signer()
+        .map_err(HandlerError::runtime)?;
+
+    state.reject_operator(signer).map_err(HandlerError::state)
+}
+
+pub async fn confirm_operator<R: ContractRuntimeContext + AccessControl, S: StateInterface>(
+    runtime: &mut R,
+    state: &mut S,
+) -> Result<(), HandlerError> {
+    runtime
+        .only_application_creator()
+        .map_err(HandlerError::runtime)?;
+    runtime
+        .only_role(Role::Admin)
+        .await
+        .map_err(HandlerError::runtime)?;
+
+    let operator = state.confirm_operator().map_err(HandlerError::state)?;
+    runtime
+        .grant_role(operator, Role::Operator)
+        .await
+        .map_err(HandlerError::runtime)
+}
+
+pub async fn update_caller<R: ContractRuntimeContext + AccessControl>(
+    runtime: &mut R,
+    caller: ApplicationId,
+) -> Result<(), HandlerError> {
+    runtime
+        .only_application_creator()
+        .map_err(HandlerError::runtime)?;
+    runtime
+        .grant_role(AccountOwner::from(caller), Role::Caller)
+        .await
+        .map_err(HandlerError::runtime)
+}
@@ -1,6 +1,9 @@
+use crate::state::types::AggregationMode;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct InstantiationArgument {
     pub top_k: u8,
+    #[serde(default)]
+    pub aggregation_mode: AggregationMode,
 }
@@ -1,3 +1,2 @@
-pub mod access_control;
 pub mod runtime;
 pub mod state;
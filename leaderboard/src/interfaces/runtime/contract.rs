@@ -1,15 +1,13 @@
-use super::base::BaseRuntimeContext;
 use crate::abi::Message;
-use linera_sdk::linera_base_types::{AccountOwner, ChainId};
+use base::interfaces::runtime::contract::ContractRuntimeContext as BaseContractRuntimeContext;
 
-pub trait ContractRuntimeContext: BaseRuntimeContext {
-    type Error;
-
-    fn authenticated_signer(&mut self) -> Option<AccountOwner>;
-    fn require_authenticated_signer(&mut self) -> Result<AccountOwner, Self::Error>;
-
-    fn send_message(&mut self, destionation: ChainId, message: Message);
+/// Contract runtime bound to the leaderboard message type
+pub trait ContractRuntimeContext:
+    BaseContractRuntimeContext<Message = Message, Parameters = ()>
+{
+}
 
-    fn message_origin_chain_id(&mut self) -> Option<ChainId>;
-    fn require_message_origin_chain_id(&mut self) -> Result<ChainId, Self::Error>;
+impl<T: BaseContractRuntimeContext<Message = Message, Parameters = ()>> ContractRuntimeContext
+    for T
+{
 }
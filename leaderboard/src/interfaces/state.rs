@@ -1,5 +1,9 @@
-use crate::instantiation_argument::InstantiationArgument;
+use crate::{
+    instantiation_argument::InstantiationArgument,
+    state::types::{AggregationMode, AuditRecord, LeaderBoardEntry},
+};
 use async_trait::async_trait;
+use base::types::Candidate;
 use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
 
 #[async_trait(?Send)]
@@ -10,11 +14,38 @@ pub trait StateInterface {
     fn instantiate(&mut self, argument: InstantiationArgument);
     fn instantiation_argument(&self) -> InstantiationArgument;
     fn top_k(&self) -> u8;
+    fn aggregation_mode(&self) -> AggregationMode;
     async fn value(&self, owner: AccountOwner) -> Self::ValueType;
-    fn update_value(
+    async fn update_value(
         &mut self,
         owner: AccountOwner,
         value: Amount,
         timestamp: Timestamp,
     ) -> Result<(), Self::Error>;
+    async fn remove_entry(&mut self, owner: AccountOwner) -> Result<(), Self::Error>;
+    fn reset_board(&mut self);
+    /// Rebuilds the ordered index from the stored values
+    async fn rebuild_ranking(&mut self) -> Result<(), Self::Error>;
+    async fn ban(&mut self, owner: AccountOwner) -> Result<(), Self::Error>;
+    fn unban(&mut self, owner: AccountOwner) -> Result<(), Self::Error>;
+    fn append_audit(&mut self, record: AuditRecord);
+    fn operator_candidate(&self) -> Option<Candidate>;
+    fn propose_operator(&mut self, owner: AccountOwner);
+    fn approve_operator(&mut self, owner: AccountOwner) -> Result<(), Self::Error>;
+    fn reject_operator(&mut self, owner: AccountOwner) -> Result<(), Self::Error>;
+    fn confirm_operator(&mut self) -> Result<AccountOwner, Self::Error>;
+}
+
+/// Read-only view of the state used by service queries
+#[async_trait]
+pub trait StateQueryInterface: Send + Sync {
+    type Error: std::fmt::Debug + std::error::Error + Send + Sync + 'static;
+
+    async fn rank(&self, owner: AccountOwner) -> Result<Option<LeaderBoardEntry>, Self::Error>;
+    async fn around(
+        &self,
+        owner: AccountOwner,
+        radius: u32,
+    ) -> Result<Vec<LeaderBoardEntry>, Self::Error>;
+    async fn page(&self, offset: u32, limit: u32) -> Result<Vec<LeaderBoardEntry>, Self::Error>;
 }
@@ -2,5 +2,4 @@ pub mod abi;
 pub mod contract_inner;
 pub mod instantiation_argument;
 pub mod interfaces;
-pub mod runtime;
 pub mod state;
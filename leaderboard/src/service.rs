@@ -7,6 +7,7 @@ use linera_sdk::{
     graphql::GraphQLMutationRoot, linera_base_types::WithServiceAbi, views::View, Service,
     ServiceRuntime,
 };
+use service_impl::QueryRoot;
 use std::sync::Arc;
 
 pub struct LeaderBoardService {
@@ -35,7 +36,7 @@ impl Service for LeaderBoardService {
 
     async fn handle_query(&self, request: Request) -> Response {
         let schema = Schema::build(
-            self.state.clone(),
+            QueryRoot::new(self.state.clone()),
             Operation::mutation_root(self.runtime.clone()),
             EmptySubscription,
         )
@@ -43,3 +44,5 @@ impl Service for LeaderBoardService {
         schema.execute(request).await
     }
 }
+
+mod service_impl;
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use async_graphql::{MergedObject, Object};
+use leaderboard::{
+    interfaces::state::StateQueryInterface,
+    state::{types::LeaderBoardEntry, LeaderBoardState},
+};
+use linera_sdk::linera_base_types::AccountOwner;
+
+pub struct RankingQuery {
+    state: Arc<LeaderBoardState>,
+}
+
+#[Object]
+impl RankingQuery {
+    /// Entry of `owner` with its position on the board
+    async fn rank(&self, owner: AccountOwner) -> async_graphql::Result<Option<LeaderBoardEntry>> {
+        Ok(self.state.rank(owner).await?)
+    }
+
+    /// Entries of up to `radius` owners ranked above and below `owner`
+    async fn around(
+        &self,
+        owner: AccountOwner,
+        radius: u32,
+    ) -> async_graphql::Result<Vec<LeaderBoardEntry>> {
+        Ok(self.state.around(owner, radius).await?)
+    }
+
+    /// Entries ranked after the first `offset` ones, at most `limit` of them
+    async fn page(&self, offset: u32, limit: u32) -> async_graphql::Result<Vec<LeaderBoardEntry>> {
+        Ok(self.state.page(offset, limit).await?)
+    }
+}
+
+#[derive(MergedObject)]
+pub struct QueryRoot(Arc<LeaderBoardState>, RankingQuery);
+
+impl QueryRoot {
+    pub fn new(state: Arc<LeaderBoardState>) -> Self {
+        Self(state.clone(), RankingQuery { state })
+    }
+}
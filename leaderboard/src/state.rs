@@ -1,25 +1,52 @@
+use std::collections::BTreeMap;
+
 use async_graphql::SimpleObject;
-use base::types::Candidate;
+use base::types::{Candidate, Role};
 use linera_sdk::{
-    linera_base_types::{AccountOwner, Amount, ChainId},
-    views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext},
+    linera_base_types::{AccountOwner, Amount},
+    views::{
+        linera_views::{self, views::ClonableView},
+        CollectionView, LogView, MapView, RegisterView, RootView, SetView, View,
+        ViewStorageContext,
+    },
 };
 
 pub mod types;
 
-use types::LeaderBoardItemValue;
+use types::{AggregationMode, AuditRecord, LeaderBoardItemValue, RankKey};
 
-#[derive(RootView, SimpleObject)]
+/// The entries `StateQueryInterface` serves are skipped by GraphQL, queries read them
+/// through the interface instead
+#[derive(RootView, ClonableView, SimpleObject)]
 #[view(context = ViewStorageContext)]
 pub struct LeaderBoardState {
+    #[graphql(skip)]
     pub _values: MapView<AccountOwner, LeaderBoardItemValue>,
+    #[graphql(skip)]
+    pub _ranking: CollectionView<u16, RankBucketView>,
+    /// Number of owners in each non-empty bucket of `_ranking`
+    #[graphql(skip)]
+    pub _bucket_sizes: RegisterView<BTreeMap<u16, u32>>,
+    #[graphql(skip)]
     pub top_owners: MapView<AccountOwner, Amount>,
 
     pub _top_k: RegisterView<u8>,
+    pub _aggregation_mode: RegisterView<AggregationMode>,
     pub operator: RegisterView<Option<Candidate>>,
-    pub caller: RegisterView<Option<ChainId>>,
+
+    pub roles: MapView<AccountOwner, Vec<Role>>,
+    pub banned: SetView<AccountOwner>,
+    pub audit_log: LogView<AuditRecord>,
+}
+
+/// Owners of one bucket of the ordered index, see [`RankKey::bucket`]
+#[derive(View, ClonableView)]
+#[view(context = ViewStorageContext)]
+pub struct RankBucketView {
+    pub entries: MapView<RankKey, ()>,
 }
 
 pub mod adapter;
 pub mod errors;
+pub mod ranking;
 pub mod state_impl;
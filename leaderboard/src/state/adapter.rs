@@ -1,12 +1,13 @@
 use std::{cell::RefCell, rc::Rc};
 
 use super::errors::StateError;
-use super::types::LeaderBoardItemValue;
+use super::types::{AggregationMode, AuditRecord, LeaderBoardItemValue};
 use crate::{
     instantiation_argument::InstantiationArgument, interfaces::state::StateInterface,
     state::LeaderBoardState,
 };
 use async_trait::async_trait;
+use base::types::Candidate;
 use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
 
 pub struct StateAdapter {
@@ -36,11 +37,15 @@ impl StateInterface for StateAdapter {
         self.state.borrow().top_k()
     }
 
+    fn aggregation_mode(&self) -> AggregationMode {
+        self.state.borrow().aggregation_mode()
+    }
+
     async fn value(&self, owner: AccountOwner) -> LeaderBoardItemValue {
         self.state.borrow().value(owner).await
     }
 
-    fn update_value(
+    async fn update_value(
         &mut self,
         owner: AccountOwner,
         value: Amount,
@@ -49,5 +54,50 @@ impl StateInterface for StateAdapter {
         self.state
             .borrow_mut()
             .update_value(owner, value, timestamp)
+            .await
+    }
+
+    async fn remove_entry(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        self.state.borrow_mut().remove_entry(owner).await
+    }
+
+    fn reset_board(&mut self) {
+        self.state.borrow_mut().reset_board()
+    }
+
+    async fn rebuild_ranking(&mut self) -> Result<(), StateError> {
+        self.state.borrow_mut().rebuild_ranking().await
+    }
+
+    async fn ban(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        self.state.borrow_mut().ban(owner).await
+    }
+
+    fn unban(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        self.state.borrow_mut().unban(owner)
+    }
+
+    fn append_audit(&mut self, record: AuditRecord) {
+        self.state.borrow_mut().append_audit(record)
+    }
+
+    fn operator_candidate(&self) -> Option<Candidate> {
+        self.state.borrow().operator_candidate()
+    }
+
+    fn propose_operator(&mut self, owner: AccountOwner) {
+        self.state.borrow_mut().propose_operator(owner)
+    }
+
+    fn approve_operator(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        self.state.borrow_mut().approve_operator(owner)
+    }
+
+    fn reject_operator(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        self.state.borrow_mut().reject_operator(owner)
+    }
+
+    fn confirm_operator(&mut self) -> Result<AccountOwner, StateError> {
+        self.state.borrow_mut().confirm_operator()
     }
 }
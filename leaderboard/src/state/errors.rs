@@ -1,8 +1,36 @@
+use base::types::CandidateState;
+use linera_sdk::{
+    linera_base_types::{AccountOwner, Amount},
+    views::ViewError,
+};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// An error that can occur during the contract execution.
-#[derive(Debug, Error)]
+#[derive(Debug, Deserialize, Error, Serialize)]
 pub enum StateError {
-    #[error("View error")]
-    ViewError(#[from] linera_sdk::views::ViewError),
+    #[error("View error: {0}")]
+    ViewError(String),
+
+    #[error("Value of {owner} overflows adding {value} to {stored}")]
+    ValueOverflow {
+        owner: AccountOwner,
+        stored: Amount,
+        value: Amount,
+    },
+
+    #[error("Owner {0} is banned")]
+    BannedOwner(AccountOwner),
+
+    #[error("No operator candidate {expected:?} in the {state:?} state")]
+    InvalidOperatorCandidate {
+        expected: Option<AccountOwner>,
+        state: CandidateState,
+    },
+}
+
+impl From<ViewError> for StateError {
+    fn from(err: ViewError) -> Self {
+        Self::ViewError(err.to_string())
+    }
 }
@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{
+    errors::StateError,
+    types::{LeaderBoardEntry, LeaderBoardItemValue, RankKey},
+    LeaderBoardState,
+};
+use crate::interfaces::state::StateQueryInterface;
+use async_trait::async_trait;
+use linera_sdk::{linera_base_types::AccountOwner, views::View};
+
+impl LeaderBoardState {
+    pub(crate) async fn reindex(
+        &mut self,
+        owner: AccountOwner,
+        previous: Option<&LeaderBoardItemValue>,
+        current: Option<&LeaderBoardItemValue>,
+    ) -> Result<(), StateError> {
+        if let Some(previous) = previous {
+            self.unindex(&RankKey::new(owner, previous)).await?;
+        }
+        if let Some(current) = current {
+            self.index(RankKey::new(owner, current)).await?;
+        }
+        Ok(())
+    }
+
+    async fn index(&mut self, key: RankKey) -> Result<(), StateError> {
+        let bucket = key.bucket();
+        self._ranking
+            .load_entry_mut(&bucket)
+            .await?
+            .entries
+            .insert(&key, ())?;
+        *self._bucket_sizes.get_mut().entry(bucket).or_default() += 1;
+        Ok(())
+    }
+
+    async fn unindex(&mut self, key: &RankKey) -> Result<(), StateError> {
+        let bucket = key.bucket();
+        match self._bucket_sizes.get_mut().get_mut(&bucket) {
+            Some(size) if *size > 1 => {
+                *size -= 1;
+                self._ranking
+                    .load_entry_mut(&bucket)
+                    .await?
+                    .entries
+                    .remove(key)?;
+            }
+            Some(_) => {
+                self._bucket_sizes.get_mut().remove(&bucket);
+                self._ranking.remove_entry(&bucket)?;
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Number of owners in the buckets ranked above `bucket`
+    fn ranked_above(&self, bucket: u16) -> u32 {
+        self._bucket_sizes
+            .get()
+            .range(bucket + 1..)
+            .map(|(_, size)| size)
+            .sum()
+    }
+
+    /// Rebuilds the ordered index and the top owners from `_values`
+    pub(crate) async fn reindex_all(&mut self) -> Result<(), StateError> {
+        let mut keys = Vec::new();
+        self._values
+            .for_each_index_value(|owner, item| {
+                keys.push(RankKey::new(owner, &item));
+                Ok(())
+            })
+            .await?;
+
+        View::clear(&mut self._ranking);
+        self._bucket_sizes.set(BTreeMap::new());
+        for key in keys {
+            self.index(key).await?;
+        }
+        self.sync_top_owners().await
+    }
+
+    /// Refreshes the top owners after the value of `owner` changed. The top owners only
+    /// change when `owner` was or now is one of them.
+    pub(crate) async fn refresh_top_owners(
+        &mut self,
+        owner: AccountOwner,
+    ) -> Result<(), StateError> {
+        let top_k = u32::from(*self._top_k.get());
+        let listed = self.top_owners.contains_key(&owner).await?;
+        let ranked = self
+            .rank(owner)
+            .await?
+            .is_some_and(|entry| entry.rank <= top_k);
+        if !listed && !ranked {
+            return Ok(());
+        }
+        self.sync_top_owners().await
+    }
+
+    /// Writes the difference between the stored top owners and the top of the index
+    async fn sync_top_owners(&mut self) -> Result<(), StateError> {
+        let entries = self.page(0, u32::from(*self._top_k.get())).await?;
+        let owners = entries
+            .iter()
+            .map(|entry| entry.owner)
+            .collect::<BTreeSet<_>>();
+
+        for owner in self.top_owners.indices().await? {
+            if !owners.contains(&owner) {
+                self.top_owners.remove(&owner)?;
+            }
+        }
+        for entry in entries {
+            if self.top_owners.get(&entry.owner).await? != Some(entry.value) {
+                self.top_owners.insert(&entry.owner, entry.value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StateQueryInterface for LeaderBoardState {
+    type Error = StateError;
+
+    /// Counts the owners of the buckets above the one of `owner`, then scans that bucket
+    async fn rank(&self, owner: AccountOwner) -> Result<Option<LeaderBoardEntry>, StateError> {
+        let Some(item) = self._values.get(&owner).await? else {
+            return Ok(None);
+        };
+        let key = RankKey::new(owner, &item);
+        let bucket = key.bucket();
+        let Some(view) = self._ranking.try_load_entry(&bucket).await? else {
+            return Ok(None);
+        };
+
+        let mut rank = self.ranked_above(bucket);
+        let mut found = false;
+        view.entries
+            .for_each_index_while(|index| {
+                rank += 1;
+                found = index == key;
+                Ok(!found)
+            })
+            .await?;
+
+        Ok(found.then(|| key.entry(rank)))
+    }
+
+    /// Entries of up to `radius` owners ranked above and below `owner`, including itself
+    async fn around(
+        &self,
+        owner: AccountOwner,
+        radius: u32,
+    ) -> Result<Vec<LeaderBoardEntry>, StateError> {
+        let Some(entry) = self.rank(owner).await? else {
+            return Ok(Vec::new());
+        };
+        let offset = entry.rank.saturating_sub(radius.saturating_add(1));
+        let limit = (entry.rank - offset).saturating_add(radius);
+        self.page(offset, limit).await
+    }
+
+    /// Skips whole buckets before `offset`, then scans only the buckets on the page
+    async fn page(&self, offset: u32, limit: u32) -> Result<Vec<LeaderBoardEntry>, StateError> {
+        let mut entries = Vec::new();
+        if limit == 0 {
+            return Ok(entries);
+        }
+
+        let mut rank = 0;
+        for (bucket, size) in self._bucket_sizes.get().iter().rev() {
+            if rank + size <= offset {
+                rank += size;
+                continue;
+            }
+            let Some(view) = self._ranking.try_load_entry(bucket).await? else {
+                rank += size;
+                continue;
+            };
+            view.entries
+                .for_each_index_while(|index| {
+                    rank += 1;
+                    if rank > offset {
+                        entries.push(index.entry(rank));
+                    }
+                    Ok(entries.len() < limit as usize)
+                })
+                .await?;
+            if entries.len() >= limit as usize {
+                break;
+            }
+        }
+
+        Ok(entries)
+    }
+}
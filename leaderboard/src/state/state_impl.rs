@@ -1,9 +1,18 @@
-use super::types::LeaderBoardItemValue;
+use std::collections::BTreeMap;
+
+use super::types::{AggregationMode, AuditRecord, LeaderBoardItemValue};
 use crate::instantiation_argument::InstantiationArgument;
 use crate::interfaces::state::StateInterface;
 use crate::state::{errors::StateError, LeaderBoardState};
 use async_trait::async_trait;
-use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
+use base::{
+    interfaces::access_control::RoleStore,
+    types::{Candidate, CandidateState, Role},
+};
+use linera_sdk::{
+    linera_base_types::{AccountOwner, Amount, Timestamp},
+    views::{View, ViewError},
+};
 
 #[async_trait(?Send)]
 impl StateInterface for LeaderBoardState {
@@ -12,11 +21,13 @@ impl StateInterface for LeaderBoardState {
 
     fn instantiate(&mut self, argument: InstantiationArgument) {
         self._top_k.set(argument.top_k);
+        self._aggregation_mode.set(argument.aggregation_mode);
     }
 
     fn instantiation_argument(&self) -> InstantiationArgument {
         InstantiationArgument {
             top_k: *self._top_k.get(),
+            aggregation_mode: *self._aggregation_mode.get(),
         }
     }
 
@@ -24,6 +35,10 @@ impl StateInterface for LeaderBoardState {
         *self._top_k.get()
     }
 
+    fn aggregation_mode(&self) -> AggregationMode {
+        *self._aggregation_mode.get()
+    }
+
     async fn value(&self, owner: AccountOwner) -> LeaderBoardItemValue {
         self._values
             .get(&owner)
@@ -32,16 +47,157 @@ impl StateInterface for LeaderBoardState {
             .unwrap_or(LeaderBoardItemValue::default())
     }
 
-    fn update_value(
+    async fn update_value(
         &mut self,
         owner: AccountOwner,
         value: Amount,
         timestamp: Timestamp,
     ) -> Result<(), StateError> {
-        self._values
-            .insert(&owner, LeaderBoardItemValue { value, timestamp })?;
+        if self.banned.contains(&owner).await? {
+            return Err(StateError::BannedOwner(owner));
+        }
+
+        let stored = self._values.get(&owner).await?;
+        let value =
+            match (&stored, self.aggregation_mode()) {
+                (None, _) | (_, AggregationMode::Replace) => value,
+                (Some(stored), AggregationMode::Accumulate) => stored
+                    .value
+                    .try_add(value)
+                    .map_err(|_| StateError::ValueOverflow {
+                        owner,
+                        stored: stored.value,
+                        value,
+                    })?,
+                (Some(stored), AggregationMode::Max) if stored.value >= value => return Ok(()),
+                (Some(stored), AggregationMode::Min) if stored.value <= value => return Ok(()),
+                (Some(_), AggregationMode::Max | AggregationMode::Min) => value,
+            };
+
+        let item = LeaderBoardItemValue { value, timestamp };
+        self.reindex(owner, stored.as_ref(), Some(&item)).await?;
+        self._values.insert(&owner, item)?;
+
+        self.refresh_top_owners(owner).await
+    }
+
+    async fn remove_entry(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        let Some(stored) = self._values.get(&owner).await? else {
+            return Ok(());
+        };
+        self.reindex(owner, Some(&stored), None).await?;
+        self._values.remove(&owner)?;
+
+        self.refresh_top_owners(owner).await
+    }
+
+    fn reset_board(&mut self) {
+        self._values.clear();
+        View::clear(&mut self._ranking);
+        self._bucket_sizes.set(BTreeMap::new());
+        self.top_owners.clear();
+    }
+
+    async fn rebuild_ranking(&mut self) -> Result<(), StateError> {
+        self.reindex_all().await
+    }
+
+    async fn ban(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        self.banned.insert(&owner)?;
+        self.remove_entry(owner).await
+    }
+
+    fn unban(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        Ok(self.banned.remove(&owner)?)
+    }
+
+    fn append_audit(&mut self, record: AuditRecord) {
+        self.audit_log.push(record);
+    }
+
+    fn operator_candidate(&self) -> Option<Candidate> {
+        self.operator.get().clone()
+    }
+
+    fn propose_operator(&mut self, owner: AccountOwner) {
+        self.operator.set(Some(Candidate {
+            owner,
+            state: CandidateState::Proposed,
+        }));
+    }
+
+    fn approve_operator(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        self.transit_operator(
+            Some(owner),
+            CandidateState::Proposed,
+            CandidateState::Approved,
+        )?;
+        Ok(())
+    }
 
-        // TODO: also insert to top_owners
+    fn reject_operator(&mut self, owner: AccountOwner) -> Result<(), StateError> {
+        self.transit_operator(
+            Some(owner),
+            CandidateState::Proposed,
+            CandidateState::Rejected,
+        )?;
         Ok(())
     }
+
+    fn confirm_operator(&mut self) -> Result<AccountOwner, StateError> {
+        self.transit_operator(None, CandidateState::Approved, CandidateState::Confirmed)
+    }
+}
+
+impl LeaderBoardState {
+    /// Moves the operator candidate from `from` to `to`, optionally checking who it is
+    fn transit_operator(
+        &mut self,
+        owner: Option<AccountOwner>,
+        from: CandidateState,
+        to: CandidateState,
+    ) -> Result<AccountOwner, StateError> {
+        let invalid = || StateError::InvalidOperatorCandidate {
+            expected: owner,
+            state: from,
+        };
+        let Some(mut candidate) = self.operator.get().clone() else {
+            return Err(invalid());
+        };
+        if candidate.state != from || owner.is_some_and(|owner| owner != candidate.owner) {
+            return Err(invalid());
+        }
+
+        candidate.state = to;
+        let owner = candidate.owner;
+        self.operator.set(Some(candidate));
+        Ok(owner)
+    }
+}
+
+#[async_trait(?Send)]
+impl RoleStore for LeaderBoardState {
+    async fn roles(&self, owner: AccountOwner) -> Result<Vec<Role>, ViewError> {
+        Ok(self.roles.get(&owner).await?.unwrap_or_default())
+    }
+
+    fn set_roles(&mut self, owner: AccountOwner, roles: Vec<Role>) -> Result<(), ViewError> {
+        match roles.is_empty() {
+            true => self.roles.remove(&owner),
+            false => self.roles.insert(&owner, roles),
+        }
+    }
+
+    async fn holders(&self, role: Role) -> Result<Vec<AccountOwner>, ViewError> {
+        let mut holders = Vec::new();
+        self.roles
+            .for_each_index_value(|owner, roles| {
+                if roles.contains(&role) {
+                    holders.push(owner);
+                }
+                Ok(())
+            })
+            .await?;
+        Ok(holders)
+    }
 }
@@ -1,5 +1,5 @@
-use async_graphql::SimpleObject;
-use linera_sdk::linera_base_types::{Amount, Timestamp};
+use async_graphql::{Enum, SimpleObject};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Deserialize, Serialize, Debug, SimpleObject)]
@@ -7,3 +7,84 @@ pub struct LeaderBoardItemValue {
     pub value: Amount,
     pub timestamp: Timestamp,
 }
+
+/// How an incoming value is combined with the stored one
+#[derive(
+    Clone, Copy, Default, Deserialize, Serialize, Debug, Enum, Eq, Ord, PartialEq, PartialOrd,
+)]
+pub enum AggregationMode {
+    /// Overwrite the stored value
+    #[default]
+    Replace,
+    /// Add the incoming value to the stored value
+    Accumulate,
+    /// Keep the greater of the stored and incoming value
+    Max,
+    /// Keep the smaller of the stored and incoming value
+    Min,
+}
+
+/// Key of the ordered index. Its BCS encoding sorts higher values first, then earlier
+/// timestamps, so iterating the index yields the board in rank order.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RankKey {
+    inverted_value: [u8; 16],
+    timestamp: [u8; 8],
+    owner: AccountOwner,
+}
+
+impl RankKey {
+    pub fn new(owner: AccountOwner, item: &LeaderBoardItemValue) -> Self {
+        Self {
+            inverted_value: (u128::MAX - u128::from(item.value)).to_be_bytes(),
+            timestamp: item.timestamp.micros().to_be_bytes(),
+            owner,
+        }
+    }
+
+    /// Bucket of the index holding this key. Buckets are ordered by the magnitude and the
+    /// leading 8 bits of the value, so a higher bucket only holds higher values.
+    pub fn bucket(&self) -> u16 {
+        let value = u128::MAX - u128::from_be_bytes(self.inverted_value);
+        let leading_zeros = value.leading_zeros();
+        let mantissa = value.checked_shl(leading_zeros).unwrap_or(0) >> 120;
+        (((128 - leading_zeros) as u16) << 8) | mantissa as u16
+    }
+
+    pub fn entry(&self, rank: u32) -> LeaderBoardEntry {
+        LeaderBoardEntry {
+            rank,
+            owner: self.owner,
+            value: Amount::from_attos(u128::MAX - u128::from_be_bytes(self.inverted_value)),
+            timestamp: Timestamp::from(u64::from_be_bytes(self.timestamp)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, SimpleObject)]
+pub struct LeaderBoardEntry {
+    /// Position on the board, starting from 1
+    pub rank: u32,
+    pub owner: AccountOwner,
+    pub value: Amount,
+    pub timestamp: Timestamp,
+}
+
+#[derive(Clone, Copy, Deserialize, Serialize, Debug, Enum, Eq, PartialEq)]
+pub enum AuditAction {
+    RemoveEntry,
+    ResetBoard,
+    Ban,
+    Unban,
+    RebuildRanking,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, SimpleObject)]
+pub struct AuditRecord {
+    pub action: AuditAction,
+    /// Account the action applies to, if any
+    pub owner: Option<AccountOwner>,
+    /// Signer who performed the action
+    pub operator: Option<AccountOwner>,
+    pub timestamp: Timestamp,
+}
@@ -0,0 +1,125 @@
+//! Fixtures shared by the tests running against an in-memory leaderboard state.
+
+#![allow(dead_code)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use base::{
+    interfaces::{
+        access_control::RoleContext,
+        runtime::{base::BaseRuntimeContext, contract::ContractRuntimeContext},
+    },
+    runtime::errors::RuntimeError,
+};
+use leaderboard::{
+    abi::Message,
+    instantiation_argument::InstantiationArgument,
+    interfaces::state::StateInterface,
+    state::{types::AggregationMode, LeaderBoardState},
+};
+use linera_sdk::{
+    abi::ContractAbi,
+    linera_base_types::{AccountOwner, ApplicationId, ChainId, CryptoHash, Timestamp},
+    views::{KeyValueStore, View, ViewStorageContext},
+};
+
+pub fn owner(name: &str) -> AccountOwner {
+    AccountOwner::from(CryptoHash::test_hash(name))
+}
+
+pub fn chain(name: &str) -> ChainId {
+    ChainId(CryptoHash::test_hash(name))
+}
+
+pub async fn new_state(top_k: u8, aggregation_mode: AggregationMode) -> LeaderBoardState {
+    let context = ViewStorageContext::new_unchecked(KeyValueStore::mock(), Vec::new(), ());
+    let mut state = LeaderBoardState::load(context)
+        .await
+        .expect("Failed to load state");
+    state.instantiate(InstantiationArgument {
+        top_k,
+        aggregation_mode,
+    });
+    state
+}
+
+/// Runtime of an operation on the creator chain, signed by `signer` at `now`, which
+/// checks roles against the state
+pub struct StubRuntime {
+    pub signer: Option<AccountOwner>,
+    pub chain_id: ChainId,
+    pub now: Timestamp,
+    pub state: Rc<RefCell<LeaderBoardState>>,
+}
+
+impl StubRuntime {
+    pub fn new(state: Rc<RefCell<LeaderBoardState>>, signer: AccountOwner) -> Self {
+        Self {
+            signer: Some(signer),
+            chain_id: chain("creator"),
+            now: Timestamp::from(1_000),
+            state,
+        }
+    }
+}
+
+impl BaseRuntimeContext for StubRuntime {
+    fn chain_id(&mut self) -> ChainId {
+        self.chain_id
+    }
+
+    fn system_time(&mut self) -> Timestamp {
+        self.now
+    }
+
+    fn application_creator_chain_id(&mut self) -> ChainId {
+        chain("creator")
+    }
+}
+
+impl ContractRuntimeContext for StubRuntime {
+    type Error = RuntimeError;
+    type Message = Message;
+    type Parameters = ();
+
+    fn authenticated_signer(&mut self) -> Option<AccountOwner> {
+        self.signer
+    }
+
+    fn require_authenticated_signer(&mut self) -> Result<AccountOwner, RuntimeError> {
+        self.signer.ok_or(RuntimeError::InvalidAuthenticatedSigner)
+    }
+
+    fn authenticated_caller_id(&mut self) -> Option<ApplicationId> {
+        None
+    }
+
+    fn send_message(&mut self, _authenticated: bool, _destination: ChainId, _message: Message) {}
+
+    fn message_origin_chain_id(&mut self) -> Option<ChainId> {
+        None
+    }
+
+    fn require_message_origin_chain_id(&mut self) -> Result<ChainId, RuntimeError> {
+        Err(RuntimeError::InvalidMessageOriginChainId)
+    }
+
+    fn application_parameters(&mut self) {}
+
+    fn call_application<A: ContractAbi + Send>(
+        &mut self,
+        _authenticated: bool,
+        _application: ApplicationId<A>,
+        _call: &A::Operation,
+    ) -> A::Response {
+        unreachable!("The stub runtime doesn't call applications")
+    }
+}
+
+impl RoleContext for StubRuntime {
+    type Roles = LeaderBoardState;
+
+    fn role_store(&self) -> Rc<RefCell<LeaderBoardState>> {
+        self.state.clone()
+    }
+}
@@ -0,0 +1,133 @@
+//! Operator gating and audit records of the leaderboard moderation operations.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use std::{cell::RefCell, rc::Rc};
+
+use base::{
+    handler::{errors::HandlerError, interfaces::HandlerFactory as _},
+    interfaces::access_control::RoleStore,
+    types::Role,
+};
+use common::{new_state, owner, StubRuntime};
+use leaderboard::{
+    abi::Operation,
+    contract_inner::handlers::HandlerFactory,
+    interfaces::state::StateInterface,
+    state::{
+        adapter::StateAdapter,
+        types::{AggregationMode, AuditAction, AuditRecord},
+        LeaderBoardState,
+    },
+};
+use linera_sdk::{
+    linera_base_types::{AccountOwner, Amount, Timestamp},
+    views::linera_views::views::ClonableView,
+};
+
+/// State with `operator` holding the operator role and `player` ranked
+async fn moderated_state(
+    operator: AccountOwner,
+    player: AccountOwner,
+) -> Rc<RefCell<LeaderBoardState>> {
+    let mut state = new_state(10, AggregationMode::Replace).await;
+    state
+        .set_roles(operator, vec![Role::Operator])
+        .expect("Failed to grant operator");
+    state
+        .update_value(player, Amount::from_tokens(5), Timestamp::from(0))
+        .await
+        .expect("Failed to update value");
+    Rc::new(RefCell::new(state))
+}
+
+async fn run(
+    state: &Rc<RefCell<LeaderBoardState>>,
+    signer: AccountOwner,
+    operation: Operation,
+) -> Result<(), HandlerError> {
+    let runtime = StubRuntime::new(state.clone(), signer);
+    HandlerFactory::new(
+        runtime,
+        StateAdapter::new(state.clone()),
+        Some(&operation),
+        None,
+    )?
+    .handle()
+    .await
+    .map(|_| ())
+}
+
+async fn audit_log(state: &Rc<RefCell<LeaderBoardState>>) -> Vec<AuditRecord> {
+    let snapshot = state
+        .borrow_mut()
+        .clone_unchecked()
+        .expect("Failed to clone state");
+    snapshot
+        .audit_log
+        .read(..)
+        .await
+        .expect("Failed to read audit log")
+}
+
+fn moderation(player: AccountOwner) -> Vec<(Operation, AuditAction, Option<AccountOwner>)> {
+    vec![
+        (
+            Operation::BanOwner { owner: player },
+            AuditAction::Ban,
+            Some(player),
+        ),
+        (
+            Operation::UnbanOwner { owner: player },
+            AuditAction::Unban,
+            Some(player),
+        ),
+        (
+            Operation::RemoveEntry { owner: player },
+            AuditAction::RemoveEntry,
+            Some(player),
+        ),
+        (Operation::RebuildRanking, AuditAction::RebuildRanking, None),
+        (Operation::ResetBoard, AuditAction::ResetBoard, None),
+    ]
+}
+
+#[tokio::test]
+async fn moderation_rejects_a_non_operator() {
+    let (operator, player) = (owner("operator"), owner("player"));
+    let state = moderated_state(operator, player).await;
+
+    for (operation, action, _) in moderation(player) {
+        let result = run(&state, player, operation).await;
+        assert!(
+            matches!(&result, Err(HandlerError::Permission(err)) if err.code == "MissingRole"),
+            "{action:?} by a non-operator: {result:?}"
+        );
+    }
+    assert!(audit_log(&state).await.is_empty());
+
+    let value = StateAdapter::new(state.clone()).value(player).await;
+    assert_eq!(value.value, Amount::from_tokens(5));
+}
+
+#[tokio::test]
+async fn moderation_appends_an_audit_record_per_action() {
+    let (operator, player) = (owner("operator"), owner("player"));
+    let state = moderated_state(operator, player).await;
+
+    for (i, (operation, action, target)) in moderation(player).into_iter().enumerate() {
+        run(&state, operator, operation)
+            .await
+            .unwrap_or_else(|err| panic!("{action:?} by the operator failed: {err}"));
+
+        let log = audit_log(&state).await;
+        assert_eq!(log.len(), i + 1);
+        let record = log.last().expect("Missing audit record");
+        assert_eq!(record.action, action);
+        assert_eq!(record.owner, target);
+        assert_eq!(record.operator, Some(operator));
+        assert_eq!(record.timestamp, Timestamp::from(1_000));
+    }
+}
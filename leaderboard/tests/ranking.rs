@@ -0,0 +1,255 @@
+//! Ordered index of the leaderboard against an in-memory state.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+mod common;
+
+use common::{new_state, owner};
+use leaderboard::{
+    interfaces::state::{StateInterface, StateQueryInterface},
+    state::{
+        errors::StateError,
+        types::{AggregationMode, LeaderBoardItemValue},
+        LeaderBoardState,
+    },
+};
+use linera_sdk::linera_base_types::{AccountOwner, Amount, Timestamp};
+
+async fn ranked_owners(state: &LeaderBoardState) -> Vec<AccountOwner> {
+    state
+        .page(0, 100)
+        .await
+        .expect("Failed to read page")
+        .into_iter()
+        .map(|entry| entry.owner)
+        .collect()
+}
+
+#[tokio::test]
+async fn rank_orders_across_buckets() {
+    let owners = (0..6)
+        .map(|i| owner(&format!("owner-{i}")))
+        .collect::<Vec<_>>();
+    let values = [
+        Amount::from_attos(3),
+        Amount::from_tokens(1_000),
+        Amount::from_tokens(5),
+        Amount::ZERO,
+        Amount::from_tokens(1_000),
+        Amount::from_millis(7),
+    ];
+    let mut state = new_state(3, AggregationMode::Replace).await;
+    for (i, (owner, value)) in owners.iter().zip(values).enumerate() {
+        state
+            .update_value(*owner, value, Timestamp::from(i as u64))
+            .await
+            .expect("Failed to update value");
+    }
+
+    // Equal values keep the earlier timestamp first
+    let expected = [1, 4, 2, 5, 0, 3]
+        .into_iter()
+        .map(|i| owners[i])
+        .collect::<Vec<_>>();
+    assert_eq!(ranked_owners(&state).await, expected);
+    for (rank, owner) in expected.iter().enumerate() {
+        let entry = state
+            .rank(*owner)
+            .await
+            .expect("Failed to read rank")
+            .expect("Missing rank");
+        assert_eq!(entry.rank as usize, rank + 1);
+    }
+
+    let page = state.page(2, 2).await.expect("Failed to read page");
+    assert_eq!(
+        page.iter().map(|entry| entry.rank).collect::<Vec<_>>(),
+        vec![3, 4]
+    );
+    let around = state
+        .around(owners[5], 1)
+        .await
+        .expect("Failed to read around");
+    assert_eq!(
+        around.iter().map(|entry| entry.owner).collect::<Vec<_>>(),
+        vec![owners[2], owners[5], owners[0]]
+    );
+    let around = state
+        .around(owners[1], 2)
+        .await
+        .expect("Failed to read around");
+    assert_eq!(
+        around.iter().map(|entry| entry.rank).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+}
+
+#[tokio::test]
+async fn top_owners_follow_updates_and_removals() {
+    let (alice, bob, carol) = (owner("alice"), owner("bob"), owner("carol"));
+    let now = Timestamp::from(0);
+    let mut state = new_state(2, AggregationMode::Replace).await;
+
+    for (owner, tokens) in [(alice, 10), (bob, 20), (carol, 5)] {
+        state
+            .update_value(owner, Amount::from_tokens(tokens), now)
+            .await
+            .expect("Failed to update value");
+    }
+    let mut top = state
+        .top_owners
+        .indices()
+        .await
+        .expect("Failed to read top owners");
+    top.sort();
+    let mut expected = vec![alice, bob];
+    expected.sort();
+    assert_eq!(top, expected);
+
+    state
+        .update_value(carol, Amount::from_tokens(30), now)
+        .await
+        .expect("Failed to update value");
+    assert!(!state.top_owners.contains_key(&alice).await.unwrap());
+    assert_eq!(
+        state.top_owners.get(&carol).await.unwrap(),
+        Some(Amount::from_tokens(30))
+    );
+
+    state
+        .remove_entry(bob)
+        .await
+        .expect("Failed to remove entry");
+    assert!(!state.top_owners.contains_key(&bob).await.unwrap());
+    assert!(state.top_owners.contains_key(&alice).await.unwrap());
+    assert_eq!(ranked_owners(&state).await, vec![carol, alice]);
+    assert!(state.rank(bob).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn rebuild_ranking_indexes_existing_values() {
+    let (alice, bob) = (owner("alice"), owner("bob"));
+    let mut state = new_state(10, AggregationMode::Replace).await;
+
+    // Values stored before the index existed
+    for (owner, tokens) in [(alice, 1), (bob, 2)] {
+        state
+            ._values
+            .insert(
+                &owner,
+                LeaderBoardItemValue {
+                    value: Amount::from_tokens(tokens),
+                    timestamp: Timestamp::from(0),
+                },
+            )
+            .expect("Failed to insert value");
+    }
+    assert!(ranked_owners(&state).await.is_empty());
+
+    state
+        .rebuild_ranking()
+        .await
+        .expect("Failed to rebuild ranking");
+    assert_eq!(ranked_owners(&state).await, vec![bob, alice]);
+    assert_eq!(
+        state.rank(alice).await.unwrap().map(|entry| entry.rank),
+        Some(2)
+    );
+    assert!(state.top_owners.contains_key(&bob).await.unwrap());
+}
+
+async fn stored_value(state: &LeaderBoardState, owner: AccountOwner) -> Amount {
+    state.value(owner).await.value
+}
+
+#[tokio::test]
+async fn accumulate_adds_to_the_stored_value() {
+    let alice = owner("alice");
+    let mut state = new_state(3, AggregationMode::Accumulate).await;
+
+    for (i, value) in [3, 4].into_iter().enumerate() {
+        state
+            .update_value(alice, Amount::from_tokens(value), Timestamp::from(i as u64))
+            .await
+            .expect("Failed to update value");
+    }
+    assert_eq!(stored_value(&state, alice).await, Amount::from_tokens(7));
+    let entry = state
+        .rank(alice)
+        .await
+        .expect("Failed to read rank")
+        .expect("Missing rank");
+    assert_eq!(entry.value, Amount::from_tokens(7));
+}
+
+#[tokio::test]
+async fn accumulate_overflow_keeps_the_stored_value() {
+    let alice = owner("alice");
+    let mut state = new_state(3, AggregationMode::Accumulate).await;
+    state
+        .update_value(alice, Amount::MAX, Timestamp::from(0))
+        .await
+        .expect("Failed to update value");
+
+    assert!(matches!(
+        state.update_value(alice, Amount::ONE, Timestamp::from(1)).await,
+        Err(StateError::ValueOverflow { owner, stored, value })
+            if owner == alice && stored == Amount::MAX && value == Amount::ONE
+    ));
+    assert_eq!(stored_value(&state, alice).await, Amount::MAX);
+}
+
+#[tokio::test]
+async fn max_and_min_keep_the_extreme_value() {
+    let alice = owner("alice");
+    // Values on the wrong side of the stored one, or equal to it, are ignored
+    for (mode, ignored, replaced_by) in [
+        (AggregationMode::Max, [3, 5], 8),
+        (AggregationMode::Min, [7, 5], 2),
+    ] {
+        let mut state = new_state(3, mode).await;
+        state
+            .update_value(alice, Amount::from_tokens(5), Timestamp::from(0))
+            .await
+            .expect("Failed to update value");
+
+        for (i, value) in ignored.into_iter().enumerate() {
+            state
+                .update_value(
+                    alice,
+                    Amount::from_tokens(value),
+                    Timestamp::from(i as u64 + 1),
+                )
+                .await
+                .expect("Failed to update value");
+        }
+        let stored = state.value(alice).await;
+        assert_eq!(stored.value, Amount::from_tokens(5), "{mode:?}");
+        assert_eq!(stored.timestamp, Timestamp::from(0), "{mode:?}");
+
+        state
+            .update_value(alice, Amount::from_tokens(replaced_by), Timestamp::from(10))
+            .await
+            .expect("Failed to update value");
+        assert_eq!(
+            stored_value(&state, alice).await,
+            Amount::from_tokens(replaced_by),
+            "{mode:?}"
+        );
+        assert_eq!(ranked_owners(&state).await, vec![alice]);
+    }
+}
+
+#[tokio::test]
+async fn replace_overwrites_the_stored_value() {
+    let alice = owner("alice");
+    let mut state = new_state(3, AggregationMode::Replace).await;
+
+    for (i, value) in [8, 2].into_iter().enumerate() {
+        state
+            .update_value(alice, Amount::from_tokens(value), Timestamp::from(i as u64))
+            .await
+            .expect("Failed to update value");
+    }
+    assert_eq!(stored_value(&state, alice).await, Amount::from_tokens(2));
+}